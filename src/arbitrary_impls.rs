@@ -0,0 +1,23 @@
+use crate::{OrdBySet, Order};
+use alloc::vec::Vec;
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+/// Generates an arbitrary list of items, which are then sorted using a default-initialized
+/// orderer so the generated set is always properly sorted.
+impl<'a, T, Orderer> Arbitrary<'a> for OrdBySet<T, Orderer>
+where
+    T: Arbitrary<'a>,
+    Orderer: Order<T> + Default,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Vec::arbitrary(u).map(Self::from)
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        Vec::arbitrary_take_rest(u).map(Self::from)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <Vec<T> as Arbitrary<'a>>::size_hint(depth)
+    }
+}
@@ -0,0 +1,27 @@
+use crate::{OrdBySet, Order};
+use alloc::vec::Vec;
+use borsh::{
+    io::{Read, Result, Write},
+    BorshDeserialize, BorshSerialize,
+};
+
+/// Serializes the items of the set as a length-prefixed sequence, in sorted order. The
+/// orderer itself is not serialized.
+impl<T: BorshSerialize, Orderer: Order<T>> BorshSerialize for OrdBySet<T, Orderer> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.storage.serialize(writer)
+    }
+}
+
+/// Deserializes a length-prefixed sequence of items, using a default-initialized orderer
+/// to sort them. The items are always re-sorted, as the order they were serialized in may
+/// not match the orderer being deserialized into.
+impl<T, Orderer> BorshDeserialize for OrdBySet<T, Orderer>
+where
+    T: BorshDeserialize,
+    Orderer: Order<T> + Default,
+{
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        Vec::deserialize_reader(reader).map(Self::from)
+    }
+}
@@ -0,0 +1,96 @@
+use crate::Order;
+use core::fmt;
+
+/// An error describing where an [`Order`] implementation was found to violate the
+/// [specification](Order#specification). Returned by
+/// [`OrdBySet::try_from_vec_checked`](crate::OrdBySet::try_from_vec_checked).
+///
+/// All indices refer to positions within the sorted items.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OrderContractError {
+    /// The item at `left` was ordered after the item at `right`, despite being sorted
+    /// before it.
+    Unsorted { left: usize, right: usize },
+    /// Comparing the items at `left` and `right` did not give the reverse of comparing
+    /// them the other way around.
+    Antisymmetry { left: usize, right: usize },
+    /// The items at `first`, `second`, and `third` were each ordered relative to the next,
+    /// but comparing `first` and `third` gave an inconsistent result.
+    Transitivity {
+        first: usize,
+        second: usize,
+        third: usize,
+    },
+}
+
+impl fmt::Display for OrderContractError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unsorted { left, right } => write!(
+                f,
+                "item {} was ordered after item {} despite being sorted before it",
+                left, right
+            ),
+            Self::Antisymmetry { left, right } => write!(
+                f,
+                "comparing items {} and {} is not antisymmetric",
+                left, right
+            ),
+            Self::Transitivity {
+                first,
+                second,
+                third,
+            } => write!(
+                f,
+                "comparing items {}, {}, and {} is not transitive",
+                first, second, third
+            ),
+        }
+    }
+}
+
+/// Checks each adjacent pair of an already-sorted slice for violations of the order
+/// specification, along with runs of three evenly spaced items for transitivity. Every
+/// run of three adjacent items is checked, then runs spaced by each power of two, so that
+/// items far apart are compared while the number of comparisons stays linear.
+pub(crate) fn check_sorted<T, Orderer: Order<T>>(
+    orderer: &Orderer,
+    items: &[T],
+) -> Result<(), OrderContractError> {
+    for (left, pair) in items.windows(2).enumerate() {
+        let right = left + 1;
+        let ordering = orderer.order_of(&pair[0], &pair[1]);
+
+        if ordering.is_gt() {
+            return Err(OrderContractError::Unsorted { left, right });
+        }
+
+        if orderer.order_of(&pair[1], &pair[0]) != ordering.reverse() {
+            return Err(OrderContractError::Antisymmetry { left, right });
+        }
+    }
+
+    let mut spacing = 1;
+    while spacing * 2 < items.len() {
+        for first in (0..items.len() - spacing * 2).step_by(spacing) {
+            let second = first + spacing;
+            let third = second + spacing;
+
+            let expected = orderer
+                .order_of(&items[first], &items[second])
+                .then(orderer.order_of(&items[second], &items[third]));
+
+            if orderer.order_of(&items[first], &items[third]) != expected {
+                return Err(OrderContractError::Transitivity {
+                    first,
+                    second,
+                    third,
+                });
+            }
+        }
+
+        spacing *= 2;
+    }
+
+    Ok(())
+}
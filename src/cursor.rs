@@ -0,0 +1,157 @@
+use crate::{OrdBySet, Order};
+use core::ops::Range;
+
+/// A cursor over an [`OrdBySet`], allowing for traversal of the set in sorted order.
+///
+/// A cursor either points to an item of the set or to a "ghost" position which sits
+/// between the last and first items. Moving past either end of the set lands on the ghost
+/// position, and moving once more wraps around to the other end. A cursor over an empty
+/// set always points to the ghost position.
+pub struct Cursor<'set, T, Orderer: Order<T>>(
+    pub(crate) &'set OrdBySet<T, Orderer>,
+    pub(crate) usize,
+);
+
+/// A cursor over an [`OrdBySet`] which allows for modifying the items it points to. Once
+/// the cursor is dropped the set is re-sorted to account for any modified items. If no
+/// items are mutably accessed, the set is not re-sorted.
+///
+/// See [`Cursor`] for how the cursor moves throughout the set.
+pub struct CursorMut<'set, T, Orderer: Order<T>>(
+    pub(crate) &'set mut OrdBySet<T, Orderer>,
+    pub(crate) usize,
+    Option<Range<usize>>,
+);
+
+fn next_index(index: usize, len: usize) -> usize {
+    if index >= len {
+        0
+    } else {
+        index + 1
+    }
+}
+
+fn prev_index(index: usize, len: usize) -> usize {
+    if index == 0 {
+        len
+    } else {
+        index - 1
+    }
+}
+
+fn peek_next<T>(storage: &[T], index: usize) -> Option<&T> {
+    if index >= storage.len() {
+        storage.first()
+    } else {
+        storage.get(index + 1)
+    }
+}
+
+fn peek_prev<T>(storage: &[T], index: usize) -> Option<&T> {
+    if index >= storage.len() {
+        storage.last()
+    } else {
+        storage.get(index.checked_sub(1)?)
+    }
+}
+
+impl<'set, T, Orderer: Order<T>> Cursor<'set, T, Orderer> {
+    /// Get the index of the item the cursor points to, or `None` if the cursor is at the
+    /// ghost position
+    pub fn index(&self) -> Option<usize> {
+        (self.1 < self.0.storage.len()).then_some(self.1)
+    }
+
+    /// Get the item the cursor points to, or `None` if the cursor is at the ghost position
+    pub fn current(&self) -> Option<&'set T> {
+        self.0.storage.get(self.1)
+    }
+
+    /// Get the item after the one the cursor points to without moving the cursor. If the
+    /// cursor is at the ghost position this is the first item of the set.
+    pub fn peek_next(&self) -> Option<&'set T> {
+        peek_next(&self.0.storage, self.1)
+    }
+
+    /// Get the item before the one the cursor points to without moving the cursor. If the
+    /// cursor is at the ghost position this is the last item of the set.
+    pub fn peek_prev(&self) -> Option<&'set T> {
+        peek_prev(&self.0.storage, self.1)
+    }
+
+    /// Move the cursor to the next item in the set
+    pub fn move_next(&mut self) {
+        self.1 = next_index(self.1, self.0.storage.len());
+    }
+
+    /// Move the cursor to the previous item in the set
+    pub fn move_prev(&mut self) {
+        self.1 = prev_index(self.1, self.0.storage.len());
+    }
+}
+
+impl<'set, T, Orderer: Order<T>> CursorMut<'set, T, Orderer> {
+    pub(crate) fn new(set: &'set mut OrdBySet<T, Orderer>) -> Self {
+        CursorMut(set, 0, None)
+    }
+
+    /// Get the index of the item the cursor points to, or `None` if the cursor is at the
+    /// ghost position
+    pub fn index(&self) -> Option<usize> {
+        (self.1 < self.0.storage.len()).then_some(self.1)
+    }
+
+    /// Get the item the cursor points to, or `None` if the cursor is at the ghost position
+    pub fn current(&self) -> Option<&T> {
+        self.0.storage.get(self.1)
+    }
+
+    /// Get a mutable reference to the item the cursor points to, or `None` if the cursor
+    /// is at the ghost position.
+    ///
+    /// **Note:** the set is not re-sorted until the cursor is dropped, so changes to the
+    /// ordering of items do not affect the position of the cursor until then.
+    pub fn current_mut(&mut self) -> Option<&mut T> {
+        let item = self.0.storage.get_mut(self.1)?;
+        let index = self.1;
+
+        // track the range spanning every item which may have been modified
+        self.2 = Some(match self.2.take() {
+            Some(touched) => touched.start.min(index)..touched.end.max(index + 1),
+            None => index..index + 1,
+        });
+
+        Some(item)
+    }
+
+    /// Get the item after the one the cursor points to without moving the cursor. If the
+    /// cursor is at the ghost position this is the first item of the set.
+    pub fn peek_next(&self) -> Option<&T> {
+        peek_next(&self.0.storage, self.1)
+    }
+
+    /// Get the item before the one the cursor points to without moving the cursor. If the
+    /// cursor is at the ghost position this is the last item of the set.
+    pub fn peek_prev(&self) -> Option<&T> {
+        peek_prev(&self.0.storage, self.1)
+    }
+
+    /// Move the cursor to the next item in the set
+    pub fn move_next(&mut self) {
+        self.1 = next_index(self.1, self.0.storage.len());
+    }
+
+    /// Move the cursor to the previous item in the set
+    pub fn move_prev(&mut self) {
+        self.1 = prev_index(self.1, self.0.storage.len());
+    }
+}
+
+impl<'set, T, Orderer: Order<T>> Drop for CursorMut<'set, T, Orderer> {
+    fn drop(&mut self) {
+        // nothing can have been modified without going through `current_mut`
+        if let Some(touched) = self.2.take() {
+            self.0.resort_range(touched);
+        }
+    }
+}
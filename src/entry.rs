@@ -0,0 +1,105 @@
+use crate::{OrdBySet, Order, SliceGuard};
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// A view into the group of items equivelant to a given item, which is either occupied
+/// or vacant. Created via [`OrdBySet::entry`].
+pub enum Entry<'set, T, Orderer: Order<T>> {
+    /// The set contains at least one equivelant item
+    Occupied(OccupiedEntry<'set, T, Orderer>),
+    /// The set contains no equivelant items
+    Vacant(VacantEntry<'set, T, Orderer>),
+}
+
+/// A view into a group of equivelant items which is present in the set
+pub struct OccupiedEntry<'set, T, Orderer: Order<T>> {
+    pub(crate) set: &'set mut OrdBySet<T, Orderer>,
+    pub(crate) key: T,
+    pub(crate) range: Range<usize>,
+}
+
+/// A view into a group of equivelant items which is not present in the set
+pub struct VacantEntry<'set, T, Orderer: Order<T>> {
+    pub(crate) set: &'set mut OrdBySet<T, Orderer>,
+    pub(crate) key: T,
+    pub(crate) index: usize,
+}
+
+impl<'set, T, Orderer: Order<T>> Entry<'set, T, Orderer> {
+    /// Get the item used to create the entry
+    pub fn key(&self) -> &T {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+}
+
+impl<'set, T, Orderer: Order<T>> OccupiedEntry<'set, T, Orderer> {
+    /// Get the item used to create the entry
+    pub fn key(&self) -> &T {
+        &self.key
+    }
+
+    /// Get the range of indices of the group within the set
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// Get the number of items in the group
+    pub fn count(&self) -> usize {
+        self.range.len()
+    }
+
+    /// Get a slice of all items in the group. No sorting order within is guaranteed.
+    pub fn get(&self) -> &[T] {
+        &self.set.storage[self.range.clone()]
+    }
+
+    /// Converts the entry into a [`SliceGuard`] over all items in the group, which
+    /// re-sorts the set once dropped.
+    ///
+    /// **Note:** the set is re-sorted when this [`SliceGuard`] is dropped. If it is
+    /// instead leaked, such as via `mem::forget`, the state of the `OrdBySet` is
+    /// unspecified until [`resort`](OrdBySet::resort) is called.
+    pub fn into_mut(self) -> SliceGuard<'set, T, Orderer> {
+        SliceGuard::new(self.set, self.range)
+    }
+
+    /// Removes all items in the group from the set, returning them
+    pub fn remove_all(self) -> Vec<T> {
+        let removed = self.set.storage.drain(self.range).collect();
+        self.set.apply_shrink_policy();
+
+        removed
+    }
+}
+
+impl<'set, T, Orderer: Order<T>> VacantEntry<'set, T, Orderer> {
+    /// Get the item used to create the entry
+    pub fn key(&self) -> &T {
+        &self.key
+    }
+
+    /// Take ownership of the item used to create the entry
+    pub fn into_key(self) -> T {
+        self.key
+    }
+
+    /// Inserts an item into the set, returning a reference to it. If the item is
+    /// equivelant to the entry's key it is inserted without searching the set again.
+    pub fn insert(self, value: T) -> &'set T {
+        let index = if self.set.orderer.order_of(&value, &self.key).is_eq() {
+            self.index
+        } else {
+            self.set
+                .search(&value)
+                .unwrap_or_else(|insert_at| insert_at)
+        };
+
+        self.set.storage.insert(index, value);
+        self.set.debug_assert_sorted();
+
+        &self.set.storage[index]
+    }
+}
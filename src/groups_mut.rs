@@ -0,0 +1,32 @@
+use crate::{OrdBySet, Order, SliceGuard};
+
+/// A mutable traversal over each group of equivelant items of an [`OrdBySet`], in order.
+/// Created via [`OrdBySet::groups_mut`].
+///
+/// As each group is borrowed from the traversal itself, this cannot implement
+/// [`Iterator`], so groups are instead yielded via [`next_group`](GroupsMut::next_group).
+pub struct GroupsMut<'set, T, Orderer: Order<T>>(
+    pub(crate) &'set mut OrdBySet<T, Orderer>,
+    pub(crate) usize,
+);
+
+impl<'set, T, Orderer: Order<T>> GroupsMut<'set, T, Orderer> {
+    /// Get a mutable slice of the next group of equivelant items, or `None` if every
+    /// group has been yielded. No sorting order within the group is guaranteed.
+    ///
+    /// Once the [`SliceGuard`] is dropped only the group itself is re-sorted, unless a
+    /// modified item no longer belongs to the group, in which case the whole set is
+    /// re-sorted. In that case which groups are yielded afterwards is unspecified, as the
+    /// boundaries of the remaining groups may have moved.
+    pub fn next_group(&mut self) -> Option<SliceGuard<'_, T, Orderer>> {
+        let start = self.1;
+        if start >= self.0.storage.len() {
+            return None;
+        }
+
+        let end = self.0.group_end(start);
+        self.1 = end;
+
+        Some(SliceGuard::new(self.0, start..end))
+    }
+}
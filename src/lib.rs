@@ -4,19 +4,19 @@
 //! ### When To Use This
 //!
 //! * When you want a [`BTreeSet`](alloc::collections::BTreeSet) but your data involves
-//! partial/loose equivelance, and you want to be able to perform efficient retrievals of
-//! multiple values of loose equivelance.
+//!   partial/loose equivelance, and you want to be able to perform efficient retrievals of
+//!   multiple values of loose equivelance.
 //! * When you have ordered keys stored in the same type as the values, allowing
-//! a [`BTreeMap`](alloc::collections::BTreeMap)-like data structure but with inline
-//! keys.
+//!   a [`BTreeMap`](alloc::collections::BTreeMap)-like data structure but with inline
+//!   keys.
 //!     * This is done by using a custom [`Order`] implementation in order to order
-//!     types by the fields being used as keys, without a reliance on being totally ordered
+//!       types by the fields being used as keys, without a reliance on being totally ordered
 //! * When you want a multi-{set, map} but hashing is not an option
 //!
 //! ### When Not To Use This
 //!
 //! * In place of `HashMap`/`HashSet`/`BTreeMap`/`BTreeSet` when you don't need multiple
-//! loosely equivelant values.
+//!   loosely equivelant values.
 //!
 //!
 //! ## Overview
@@ -92,20 +92,77 @@
 //! ```
 //!
 //! [zero-sized type]: https://doc.rust-lang.org/nomicon/exotic-sizes.html#zero-sized-types-zsts
+//!
+//! ## Optional Features
+//!
+//! * `serde` - implements `Serialize` and `Deserialize` for [`OrdBySet`]. The orderer is
+//!   not serialized, and is instead default-initialized when deserializing.
+//! * `rayon` - implements `IntoParallelIterator` for [`OrdBySet`] (owned, by reference,
+//!   and by mutable reference) as well as `FromParallelIterator`.
+//! * `arbitrary` - implements `Arbitrary` for [`OrdBySet`], for use in fuzzing. The
+//!   orderer is default-initialized and the generated items are sorted by it.
+//! * `proptest` - provides `ord_by_set_strategy` for generating sets in property tests.
+//!   The orderer must implement `Default`, and the generated items are sorted by it.
+//! * `quickcheck` - implements quickcheck's `Arbitrary` for [`OrdBySet`]. The orderer is
+//!   default-initialized, and both generated and shrunk items are sorted by it.
+//! * `rkyv` - implements rkyv's `Archive`, `Serialize`, and `Deserialize` for
+//!   [`OrdBySet`], archiving its items as an `ArchivedVec`. Deserializing re-sorts the
+//!   items using a default-initialized orderer, while `OrdBySet::from_archived_sorted`
+//!   trusts the archived order.
+//! * `borsh` - implements `BorshSerialize` and `BorshDeserialize` for [`OrdBySet`],
+//!   with the same behavior as the `serde` feature.
 #![no_std]
-use core::ops::Range;
+use core::{
+    cmp::Ordering,
+    ops::{Bound, Range, RangeBounds},
+};
 
 extern crate alloc;
-use alloc::vec::Vec;
+use alloc::{collections::TryReserveError, vec::Vec};
 
+mod contract;
+mod cursor;
+mod entry;
+mod groups_mut;
 mod mut_ref_guard;
 mod order;
+mod set_ops;
+mod shrink_policy;
 mod slice_guard;
 mod trait_impls;
 
+#[cfg(feature = "serde")]
+mod serde_impls;
+
+#[cfg(feature = "rayon")]
+mod rayon_impls;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impls;
+
+#[cfg(feature = "proptest")]
+mod strategy;
+
+#[cfg(feature = "quickcheck")]
+mod quickcheck_impls;
+
+#[cfg(feature = "rkyv")]
+mod rkyv_impls;
+
+#[cfg(feature = "borsh")]
+mod borsh_impls;
+
+#[cfg(feature = "proptest")]
+pub use strategy::ord_by_set_strategy;
+
 pub use {
+    contract::OrderContractError,
+    cursor::{Cursor, CursorMut},
+    entry::{Entry, OccupiedEntry, VacantEntry},
+    groups_mut::GroupsMut,
     mut_ref_guard::MutRefGuard,
-    order::{FullOrd, Order},
+    order::{ChainedOrder, FullOrd, Order, OrderByKey, ReverseOrder},
+    shrink_policy::ShrinkPolicy,
     slice_guard::SliceGuard,
 };
 
@@ -114,13 +171,14 @@ mod tests;
 
 /// A multi-set backed by a sorted list of items while allowing for a custom
 /// ordering scheme.
-#[derive(Clone, Hash)]
+#[derive(Clone)]
 pub struct OrdBySet<T, Orderer = FullOrd>
 where
     Orderer: Order<T>,
 {
     storage: Vec<T>,
     orderer: Orderer,
+    shrink_policy: ShrinkPolicy,
 }
 
 impl<T, Orderer: Order<T> + Default> OrdBySet<T, Orderer> {
@@ -143,9 +201,92 @@ impl<T, Orderer: Order<T>> OrdBySet<T, Orderer> {
         Self {
             storage: Vec::new(),
             orderer,
+            shrink_policy: ShrinkPolicy::Never,
+        }
+    }
+
+    /// Create an `OrdBySet` from a `Vec` which is already sorted according to `orderer`,
+    /// skipping the sort performed by [`with_items`](OrdBySet::with_items) and `From<Vec<T>>`.
+    ///
+    /// **Note:** the sortedness of `storage` is not checked. If it is not sorted by `orderer`
+    /// then the behavior of the resulting set is unspecified (albeit safe/sound in the
+    /// context of Rust's safety guarantees). In debug builds the set is checked after each
+    /// insertion or mutation, so these will panic until [`resort`](OrdBySet::resort) is
+    /// called.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::{FullOrd, OrdBySet};
+    ///
+    /// let set = OrdBySet::from_sorted_unchecked(FullOrd, vec![1, 2, 2, 3]);
+    /// assert_eq!(set.count(&2), 2);
+    /// ```
+    pub fn from_sorted_unchecked(orderer: Orderer, storage: Vec<T>) -> Self {
+        Self {
+            storage,
+            orderer,
+            shrink_policy: ShrinkPolicy::Never,
         }
     }
 
+    /// Create an `OrdBySet` from an iterator which yields items already sorted according to
+    /// `orderer`, such as a merge of several sorted sources. This is the same as
+    /// [`from_sorted_unchecked`](OrdBySet::from_sorted_unchecked), but for iterators.
+    ///
+    /// **Note:** the sortedness of `iter` is only checked in debug builds, where a panic
+    /// occurs if it is not sorted. Otherwise if it is not sorted by `orderer` then the
+    /// behavior of the resulting set is unspecified (albeit safe/sound in the context of
+    /// Rust's safety guarantees).
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::{FullOrd, OrdBySet};
+    ///
+    /// let set = OrdBySet::from_sorted_iter(FullOrd, (0..5).map(|i| i / 2));
+    /// assert_eq!(set.count(&1), 2);
+    /// ```
+    pub fn from_sorted_iter<I: IntoIterator<Item = T>>(orderer: Orderer, iter: I) -> Self {
+        let set = Self::from_sorted_unchecked(orderer, iter.into_iter().collect());
+        set.debug_assert_sorted();
+
+        set
+    }
+
+    /// Create an `OrdBySet` by sorting `items` according to `orderer`, then checking the
+    /// sorted items for violations of the [`Order` specification](Order#specification).
+    /// Each adjacent pair is checked for antisymmetry, and runs of three items are checked
+    /// for transitivity, both adjacent runs and a sample of runs spaced further apart.
+    ///
+    /// This is intended for debugging misbehaving orderers in tests and debug builds, as
+    /// it performs extra comparisons compared to [`with_items`](OrdBySet::with_items). A
+    /// successful check does not guarantee that the orderer upholds the specification for
+    /// every possible pair of items.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::{OrdBySet, OrderContractError};
+    /// use std::cmp::Ordering;
+    ///
+    /// let set = OrdBySet::try_from_vec_checked(|l: &i32, r: &i32| l.cmp(r), vec![3, 1, 2]);
+    /// assert!(set.is_ok());
+    ///
+    /// let always_less = |_: &i32, _: &i32| Ordering::Less;
+    /// let set = OrdBySet::try_from_vec_checked(always_less, vec![3, 1, 2]);
+    /// assert_eq!(set.unwrap_err(), OrderContractError::Antisymmetry { left: 0, right: 1 });
+    /// ```
+    pub fn try_from_vec_checked(
+        orderer: Orderer,
+        mut items: Vec<T>,
+    ) -> Result<Self, OrderContractError> {
+        orderer.sort_slice(&mut items);
+        contract::check_sorted(&orderer, &items)?;
+
+        Ok(Self::from_sorted_unchecked(orderer, items))
+    }
+
     /// Inserts an item into the set. This operation is more efficient when items are
     /// inserted in-order due to being backed by contiguous memory (a `Vec`), and thus
     /// shares a lot of the same performance properties of `Vec`.
@@ -165,237 +306,1975 @@ impl<T, Orderer: Order<T>> OrdBySet<T, Orderer> {
     /// assert_eq!(set.count(&1), 2);
     /// ```
     pub fn insert(&mut self, item: T) {
-        let insertion_point = self
-            .storage
-            .binary_search_by(|x| self.orderer.order_of(&x, &item))
-            .unwrap_or_else(|insert_at| insert_at);
+        let insertion_point = self.search(&item).unwrap_or_else(|insert_at| insert_at);
 
         self.storage.insert(insertion_point, item);
+        self.debug_assert_sorted();
     }
 
-    fn get_index_range_of(&self, item: &T) -> Option<Range<usize>> {
-        let start = self
-            .storage
-            .partition_point(|probe| self.orderer.order_of(&probe, &item).is_lt());
-        let len = self.storage[start..]
-            .partition_point(|probe| self.orderer.order_of(&probe, &item).is_eq());
-        let end = start + len;
+    /// Inserts an item into the set, the same as [`insert`](OrdBySet::insert), but returns
+    /// an error instead of aborting if the space for the item cannot be allocated. If an
+    /// error is returned, the set is left unchanged and the item is dropped.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let mut set = OrdBySet::fully_ordered();
+    /// set.try_insert(3).unwrap();
+    /// set.try_insert(1).unwrap();
+    ///
+    /// assert_eq!(set.as_slice(), [1, 3]);
+    /// ```
+    pub fn try_insert(&mut self, item: T) -> Result<(), TryReserveError> {
+        self.storage.try_reserve(1)?;
+        self.insert(item);
 
-        (end > start).then(|| start..end)
+        Ok(())
     }
 
-    /// Removes all values from the set where the orderer determines the value is
-    /// equal to the provided item. Returns `true` if any items were removed.
-    pub fn remove_all(&mut self, item: &T) -> bool {
-        if let Some(range) = self.get_index_range_of(item) {
-            // drop to ensure elements are removed immediately.
-            drop(self.storage.drain(range));
+    /// Inserts an item into the set only if no equivelant item is already present.
+    /// Returns `true` if the item was inserted, otherwise the item is dropped and `false`
+    /// is returned.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let mut set = OrdBySet::fully_ordered();
+    ///
+    /// assert!(set.insert_unique(1));
+    /// assert!(!set.insert_unique(1));
+    /// assert_eq!(set.len(), 1);
+    /// ```
+    pub fn insert_unique(&mut self, item: T) -> bool {
+        match self.search(&item) {
+            Ok(_) => false,
+            Err(insertion_point) => {
+                self.storage.insert(insertion_point, item);
+                self.debug_assert_sorted();
 
-            true
-        } else {
-            false
+                true
+            }
         }
     }
 
-    /// Removes the first value from the set where the orderer determines the value is
-    /// equal to the provided item. Returns the item if it is removed.
-    pub fn remove_first(&mut self, item: &T) -> Option<T> {
-        let location_range = self.get_index_range_of(item)?;
-        let contains_item = !location_range.is_empty();
+    /// Replaces the first item equivelant to the provided item, returning the replaced
+    /// item. If no equivelant item is present, the item is inserted and `None` is
+    /// returned.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let by_len = |left: &&str, right: &&str| left.len().cmp(&right.len());
+    /// let mut set = OrdBySet::new_with_order(by_len).with_items(["a", "bb"]);
+    ///
+    /// assert_eq!(set.replace_first("c"), Some("a"));
+    /// assert_eq!(set.replace_first("ddd"), None);
+    /// assert_eq!(set.into_vec(), ["c", "bb", "ddd"]);
+    /// ```
+    pub fn replace_first(&mut self, item: T) -> Option<T> {
+        let index = self.lower_bound(&item);
 
-        contains_item.then(|| self.storage.remove(location_range.start))
-    }
+        let replaced = match self.storage.get_mut(index) {
+            Some(existing) if self.orderer.order_of(existing, &item).is_eq() => {
+                Some(core::mem::replace(existing, item))
+            }
+            _ => {
+                self.storage.insert(index, item);
 
-    /// Removes all equivelant values from the set, returning all the items which
-    /// were found to be equal and removed.
-    pub fn drain(&mut self, item: &T) -> Vec<T> {
-        self.get_index_range_of(item)
-            .map(|range| self.storage.drain(range).collect())
-            .unwrap_or_default()
-    }
+                None
+            }
+        };
+        self.debug_assert_sorted();
 
-    /// Retains only the elements specified by the predicate, removing all elements
-    /// where the provided predicate returns `false`.
-    pub fn retain<F>(&mut self, f: F)
-    where
-        F: FnMut(&T) -> bool,
-    {
-        self.storage.retain(f)
+        replaced
     }
 
-    /// Get a slice of all equivelant items. No sorting order within is guaranteed.
+    /// Get the [`Entry`] for the group of items equivelant to the provided item, allowing
+    /// for inspecting, modifying, or inserting into the group without repeatedly
+    /// searching the set.
     ///
-    /// Returns `None` if no matching items were found in the set.
-    pub fn get(&self, item: &T) -> Option<&[T]> {
-        Some(&self.storage[self.get_index_range_of(item)?])
-    }
-
-    /// Get the first item in the set found while binary searching for a given equivelant
-    /// no guarantee is found that the item is the first in contiguous memory, rather,
-    /// this finds the quickest item to be found.
-    pub fn get_first(&self, item: &T) -> Option<&T> {
-        let index = self
-            .storage
-            .binary_search_by(|x| self.orderer.order_of(&x, item))
-            .ok()?;
-
-        self.storage.get(index)
-    }
-
-    /// Get a slice of all equivelant items. No sorting order within is guaranteed
+    /// ### Example
     ///
-    /// **Note:** the state of the `OrdBySet` is unspecified if this [`SliceGuard`] is
-    /// not dropped, such as via `mem::forget`.
-    pub fn get_mut(&mut self, item: &T) -> Option<SliceGuard<'_, T, Orderer>> {
-        let range = self.get_index_range_of(item)?;
+    /// ```
+    /// use ord_by_set::{Entry, OrdBySet};
+    ///
+    /// let mut set = OrdBySet::fully_ordered().with_items([1, 2, 2]);
+    ///
+    /// match set.entry(2) {
+    ///     Entry::Occupied(entry) => assert_eq!(entry.remove_all(), [2, 2]),
+    ///     Entry::Vacant(_) => unreachable!(),
+    /// }
+    ///
+    /// match set.entry(3) {
+    ///     Entry::Occupied(_) => unreachable!(),
+    ///     Entry::Vacant(entry) => assert_eq!(entry.insert(3), &3),
+    /// }
+    ///
+    /// assert_eq!(set.into_vec(), [1, 3]);
+    /// ```
+    pub fn entry(&mut self, item: T) -> Entry<'_, T, Orderer> {
+        let range = self.lower_bound(&item)..self.upper_bound(&item);
 
-        Some(SliceGuard(self, range))
+        if range.is_empty() {
+            Entry::Vacant(VacantEntry {
+                set: self,
+                key: item,
+                index: range.start,
+            })
+        } else {
+            Entry::Occupied(OccupiedEntry {
+                set: self,
+                key: item,
+                range,
+            })
+        }
     }
 
-    /// Get a mutable reference to the first item in the set found while binary searching
-    /// for a given equivelant no guarantee is found that the item is the first in
-    /// contiguous memory, rather, this finds the quickest item to be found.
+    /// Get the first item equivelant to the provided item, or if there is no such item
+    /// then insert the provided item and get a reference to it. If an equivelant item is
+    /// present, the provided item is dropped.
     ///
-    /// **Note:** the state of the `OrdBySet` is unspecified if this [`MutRefGuard`] is
-    /// not dropped, such as via `mem::forget`.
-    pub fn get_first_mut(&mut self, item: &T) -> Option<MutRefGuard<'_, T, Orderer>> {
-        let index = self
-            .storage
-            .binary_search_by(|x| self.orderer.order_of(&x, item))
-            .ok()?;
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let by_len = |left: &&str, right: &&str| left.len().cmp(&right.len());
+    /// let mut set = OrdBySet::new_with_order(by_len).with_items(["a"]);
+    ///
+    /// assert_eq!(set.get_or_insert("b"), &"a");
+    /// assert_eq!(set.get_or_insert("bb"), &"bb");
+    /// assert_eq!(set.len(), 2);
+    /// ```
+    pub fn get_or_insert(&mut self, item: T) -> &T {
+        let index = self.lower_bound(&item);
+        let present = matches!(
+            self.storage.get(index),
+            Some(probe) if self.orderer.order_of(probe, &item).is_eq()
+        );
 
-        Some(MutRefGuard(self, index))
-    }
+        if !present {
+            self.storage.insert(index, item);
+            self.debug_assert_sorted();
+        }
 
-    /// Check if an equivelant item is contained in the set
-    pub fn contains(&self, item: &T) -> bool {
-        self.storage
-            .binary_search_by(|x| self.orderer.order_of(&x, item))
-            .is_ok()
+        &self.storage[index]
     }
 
-    /// Check the number of equivelant items contained in the set
-    pub fn count(&self, item: &T) -> usize {
-        self.get_index_range_of(item)
-            .map(|range| range.len())
-            .unwrap_or(0)
-    }
+    /// Get the first item equivelant to the provided item, or if there is no such item
+    /// then insert the item returned by `f` and get a reference to it. `f` is only called
+    /// if no equivelant item is present.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let by_len = |left: &String, right: &String| left.len().cmp(&right.len());
+    /// let mut set = OrdBySet::new_with_order(by_len).with_items(["a".to_owned()]);
+    ///
+    /// let key = "b".to_owned();
+    /// assert_eq!(set.get_or_insert_with(&key, || key.clone()), "a");
+    ///
+    /// let key = "bb".to_owned();
+    /// assert_eq!(set.get_or_insert_with(&key, || key.clone()), "bb");
+    /// assert_eq!(set.len(), 2);
+    /// ```
+    pub fn get_or_insert_with<F>(&mut self, item: &T, f: F) -> &T
+    where
+        F: FnOnce() -> T,
+    {
+        let index = match self.get_index_range_of(item) {
+            Some(range) => range.start,
+            None => {
+                let new_item = f();
+                let index = self.search(&new_item).unwrap_or_else(|insert_at| insert_at);
+                self.storage.insert(index, new_item);
+                self.debug_assert_sorted();
 
-    /// Returns an iterator over all of the elements in no specified order
-    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
-        self.storage.iter()
-    }
+                index
+            }
+        };
 
-    /// Returns an iterator over all of the elements in no specified order such that
-    /// each value can be modified.
-    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> + '_ {
-        self.storage.iter_mut()
+        &self.storage[index]
     }
 
-    /// Replaces the contents of the set with the contents of a `Vec`
+    /// Binary searches the set for an equivelant item. If one is found then `Ok` is
+    /// returned containing its index, otherwise `Err` is returned containing the index
+    /// the item could be inserted at while keeping the set sorted.
     ///
-    /// ## Example
+    /// **Note:** if the item's group contains multiple items, the index returned may be
+    /// that of any item within the group, rather than the first.
+    ///
+    /// ### Example
     ///
     /// ```
     /// use ord_by_set::OrdBySet;
     ///
-    /// let set: OrdBySet<u64> = OrdBySet::new().with_items([3, 1, 3, 2]);
-    /// assert_eq!(set.count(&3), 2);
+    /// let set = OrdBySet::fully_ordered().with_items([1, 3, 5]);
+    ///
+    /// assert_eq!(set.search(&3), Ok(1));
+    /// assert_eq!(set.search(&4), Err(2));
     /// ```
-    pub fn with_items<Items: Into<Vec<T>>>(self, items: Items) -> Self {
-        let mut storage = items.into();
-        self.orderer.sort_slice(&mut storage);
-
-        Self { storage, ..self }
-    }
-
-    /// Get the number of items in the set
-    pub fn len(&self) -> usize {
-        self.storage.len()
+    pub fn search(&self, item: &T) -> Result<usize, usize> {
+        self.storage
+            .binary_search_by(|probe| self.orderer.order_of(probe, item))
     }
 
-    /// Returns the number of elements the set can hold without reallocating
-    pub fn capacity(&self) -> usize {
-        self.storage.capacity()
+    /// Get the index of the first item in the set which is not less than the provided
+    /// item. If an equivelant item is present this is the start of its group, otherwise
+    /// it is the index the item would be inserted at.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let set = OrdBySet::fully_ordered().with_items([1, 2, 2, 4]);
+    ///
+    /// assert_eq!(set.lower_bound(&2), 1);
+    /// assert_eq!(set.upper_bound(&2), 3);
+    /// assert_eq!(set.lower_bound(&3), set.upper_bound(&3));
+    /// ```
+    pub fn lower_bound(&self, item: &T) -> usize {
+        self.storage
+            .partition_point(|probe| self.orderer.order_of(probe, item).is_lt())
     }
 
-    /// Remove all items in the set
-    pub fn clear(&mut self) {
-        self.storage.truncate(0);
+    /// Get the index of the first item in the set which is greater than the provided
+    /// item. If an equivelant item is present this is the end of its group, otherwise
+    /// it is the index the item would be inserted at.
+    pub fn upper_bound(&self, item: &T) -> usize {
+        self.storage
+            .partition_point(|probe| self.orderer.order_of(probe, item).is_le())
     }
 
-    /// Checks if there are any items inside the set
-    pub fn is_empty(&self) -> bool {
-        self.storage.is_empty()
+    fn get_index_range_of(&self, item: &T) -> Option<Range<usize>> {
+        self.index_range_by(|probe| self.orderer.order_of(probe, item))
     }
 
-    fn range_to_index_range(&self, low: &T, high: &T) -> Option<Range<usize>> {
-        if !self.orderer.order_of(low, high).is_lt() {
+    /// Get the range of items for which `cmp` returns `Ordering::Equal`, where `cmp`
+    /// orders an item of the set relative to the item being searched for
+    fn index_range_by<F>(&self, cmp: F) -> Option<Range<usize>>
+    where
+        F: Fn(&T) -> Ordering,
+    {
+        if self.storage.is_empty() {
             return None;
         }
 
-        let start = self
-            .storage
-            .partition_point(|probe| self.orderer.order_of(probe, low).is_lt());
+        let start = self.storage.partition_point(|probe| cmp(probe).is_lt());
+        let len = self.storage[start..].partition_point(|probe| cmp(probe).is_eq());
+        let end = start + len;
 
-        let len = self.storage[start..]
-            .partition_point(|probe| self.orderer.order_of(probe, high).is_le());
+        (end > start).then_some(start..end)
+    }
 
-        let end = start + len;
+    /// Merges items which are already sorted according to the orderer into the set in
+    /// linear time. Merged items are placed after any equivelant items already present.
+    fn merge_in<I: IntoIterator<Item = T>>(&mut self, sorted: I) {
+        let mut sorted = sorted.into_iter().peekable();
+        let existing = core::mem::take(&mut self.storage);
+        let mut merged = Vec::with_capacity(existing.len() + sorted.size_hint().0);
+        let mut existing = existing.into_iter().peekable();
+
+        loop {
+            let next = match (existing.peek(), sorted.peek()) {
+                (Some(left), Some(right)) if self.orderer.order_of(left, right).is_gt() => {
+                    sorted.next()
+                }
+                (Some(_), _) => existing.next(),
+                (None, _) => sorted.next(),
+            };
+
+            match next {
+                Some(item) => merged.push(item),
+                None => break,
+            }
+        }
 
-        (end > start).then(|| start..end)
+        self.storage = merged;
+        self.debug_assert_sorted();
     }
 
-    /// Gets a slice of all elements inclusively between two bounds
-    pub fn range(&self, low: &T, high: &T) -> Option<&[T]> {
-        self.range_to_index_range(low, high)
-            .map(|range| &self.storage[range])
+    /// Removes every item within `range` for which `keep` returns `false`, moving the kept
+    /// items to the start of the range in their original order. `keep` is passed the set
+    /// and the index of an item, and may look at any item from that index onwards. The
+    /// shrink policy is applied afterwards, and the number of items removed is returned.
+    fn retain_indices<F>(&mut self, range: Range<usize>, mut keep: F) -> usize
+    where
+        F: FnMut(&Self, usize) -> bool,
+    {
+        let mut kept = range.start;
+
+        // every item before `i` has already been visited, so moving kept items to the
+        // front only disturbs items which are never looked at again
+        for i in range.clone() {
+            if keep(self, i) {
+                self.storage.swap(kept, i);
+                kept += 1;
+            }
+        }
+
+        // drop to ensure elements are removed immediately.
+        drop(self.storage.drain(kept..range.end));
+        self.apply_shrink_policy();
+
+        range.end - kept
     }
 
-    /// Gets a mutable slice of all elements between two bounds
-    pub fn range_mut(&mut self, low: &T, high: &T) -> Option<SliceGuard<'_, T, Orderer>> {
-        self.range_to_index_range(low, high)
-            .map(move |range| SliceGuard(self, range))
+    /// Get the end of the group of items equivelant to the item at index `start`
+    fn group_end(&self, start: usize) -> usize {
+        let first = &self.storage[start];
+        let len = self.storage[start..]
+            .partition_point(|probe| self.orderer.order_of(probe, first).is_eq());
+
+        start + len
     }
-}
 
-impl<T, Orderer: Order<T>> OrdBySet<T, Orderer>
-where
-    T: PartialEq,
-{
-    /// Searches for a specific item (based on `PartialEq`) and removes it, returning it
-    /// if it exists.
-    ///
-    /// If multiple exist, the first found is removed.
-    ///
-    /// **Note:** this method assumes that the equality of `Orderer` is a superset of
-    /// `PartialEq`. That is to say that if `x == y` (`PartialEq`), then
-    /// `orderer.order_of(&x, &y)` must return `Ordering::Equal`.
-    pub fn remove_specific(&mut self, val: &T) -> Option<T> {
-        let location_range = self.get_index_range_of(val)?;
-        let start = location_range.start;
-        let index = self.storage[location_range].iter().position(|x| x == val)? + start;
+    /// Get the start of the group of items equivelant to the item at index `last`
+    fn group_start(&self, last: usize) -> usize {
+        let item = &self.storage[last];
 
-        Some(self.storage.remove(index))
+        self.storage[..last].partition_point(|probe| self.orderer.order_of(probe, item).is_lt())
     }
 
-    /// Searches for a specific item (based on `PartialEq`) and returns a reference to it.
-    ///
-    /// If multiple exist, the first found is returned.
-    ///
-    /// **Note:** this method assumes that the equality of `Orderer` is a superset of
+    /// Removes all values from the set where the orderer determines the value is
+    /// equal to the provided item. Returns `true` if any items were removed.
+    pub fn remove_all(&mut self, item: &T) -> bool {
+        if let Some(range) = self.get_index_range_of(item) {
+            // drop to ensure elements are removed immediately.
+            drop(self.storage.drain(range));
+            self.apply_shrink_policy();
+
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Removes the first value from the set where the orderer determines the value is
+    /// equal to the provided item. Returns the item if it is removed.
+    ///
+    /// The item removed is the first item of its group, the same item returned by
+    /// [`get_first`](OrdBySet::get_first).
+    pub fn remove_first(&mut self, item: &T) -> Option<T> {
+        let location_range = self.get_index_range_of(item)?;
+        let contains_item = !location_range.is_empty();
+
+        contains_item.then(|| self.storage.remove(location_range.start))
+    }
+
+    /// Removes the first value from the set where the orderer determines the value is
+    /// equal to the provided item, the same as [`remove_first`](OrdBySet::remove_first),
+    /// but by swapping the last item of the set into its place rather than shifting every
+    /// following item. Returns the item if it is removed.
+    ///
+    /// **Note:** as swapping breaks the ordering of the set, the items following the
+    /// removed item are then re-sorted, making this O(n log n) in the length of that tail
+    /// rather than the O(n) shift performed by `remove_first`. Unlike `remove_first` this
+    /// also requires comparing items, so it is only worthwhile when items are expensive
+    /// to move but cheap to compare.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let mut set = OrdBySet::fully_ordered().with_items([1, 2, 3, 4]);
+    ///
+    /// assert_eq!(set.swap_remove_first(&2), Some(2));
+    /// assert_eq!(set.as_slice(), [1, 3, 4]);
+    /// assert_eq!(set.swap_remove_first(&2), None);
+    /// ```
+    pub fn swap_remove_first(&mut self, item: &T) -> Option<T> {
+        let index = self.get_index_range_of(item)?.start;
+        let removed = self.storage.swap_remove(index);
+
+        self.orderer.sort_slice(&mut self.storage[index..]);
+
+        Some(removed)
+    }
+
+    /// Removes all equivelant values from the set, returning all the items which
+    /// were found to be equal and removed.
+    pub fn drain(&mut self, item: &T) -> Vec<T> {
+        let drained = self
+            .get_index_range_of(item)
+            .map(|range| self.storage.drain(range).collect())
+            .unwrap_or_default();
+        self.apply_shrink_policy();
+
+        drained
+    }
+
+    /// Removes every item from the set, returning an iterator over the removed items in
+    /// sorted order. The allocated capacity of the set is kept, allowing it to be reused.
+    ///
+    /// If the iterator is dropped before being fully consumed, the remaining items are
+    /// still removed.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let mut set = OrdBySet::fully_ordered().with_items([2, 3, 1]);
+    /// let capacity = set.capacity();
+    ///
+    /// assert_eq!(set.drain_all().collect::<Vec<_>>(), [1, 2, 3]);
+    /// assert!(set.is_empty());
+    /// assert_eq!(set.capacity(), capacity);
+    /// ```
+    pub fn drain_all(&mut self) -> alloc::vec::Drain<'_, T> {
+        self.storage.drain(..)
+    }
+
+    /// Moves all items from `other` into `self`, leaving `other` empty. As both sets are
+    /// already sorted, this is done by merging them in linear time.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let mut set = OrdBySet::fully_ordered().with_items([2, 4]);
+    /// let mut other = OrdBySet::fully_ordered().with_items([1, 3, 5]);
+    /// set.append(&mut other);
+    ///
+    /// assert_eq!(set.iter().copied().collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+    /// assert!(other.is_empty());
+    /// ```
+    pub fn append(&mut self, other: &mut Self) {
+        self.merge_in(other.storage.drain(..));
+    }
+
+    /// Merges items which are already sorted according to the orderer into the set in
+    /// linear time, rather than inserting each item individually. Merged items are placed
+    /// after any equivelant items already present.
+    ///
+    /// **Note:** the sortedness of `sorted` is only checked in debug builds, where a panic
+    /// occurs if the set is not sorted after merging. Otherwise if it is not sorted by the
+    /// orderer then the behavior of the set is unspecified (albeit safe/sound in the
+    /// context of Rust's safety guarantees).
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let mut set = OrdBySet::fully_ordered().with_items([2, 4, 6]);
+    /// set.merge_sorted(3..6);
+    ///
+    /// assert_eq!(set.as_slice(), [2, 3, 4, 4, 5, 6]);
+    /// ```
+    pub fn merge_sorted<I: IntoIterator<Item = T>>(&mut self, sorted: I) {
+        self.merge_in(sorted);
+    }
+
+    /// Inserts a batch of items into the set by sorting the batch on its own and then
+    /// merging it into the set in linear time. This is typically faster than inserting
+    /// each item individually, while avoiding re-sorting the items already in the set.
+    /// Inserted items are placed after any equivelant items already present.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let mut set = OrdBySet::fully_ordered().with_items([2, 4, 6]);
+    /// set.insert_many([5, 1, 4]);
+    ///
+    /// assert_eq!(set.as_slice(), [1, 2, 4, 4, 5, 6]);
+    /// ```
+    pub fn insert_many<I: IntoIterator<Item = T>>(&mut self, items: I) {
+        let mut items = items.into_iter().collect::<Vec<_>>();
+        self.orderer.sort_slice(&mut items);
+
+        self.merge_in(items);
+    }
+
+    /// Retains only the elements specified by the predicate, removing all elements
+    /// where the provided predicate returns `false`.
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.storage.retain(f)
+    }
+
+    /// Retains only the elements specified by the predicate, removing all elements
+    /// where the provided predicate returns `false`, while allowing each element to be
+    /// modified.
+    ///
+    /// Unlike [`retain`](OrdBySet::retain), the set is always re-sorted afterwards to
+    /// account for any changes in ordering made by the predicate.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let mut set = OrdBySet::fully_ordered().with_items([1, 2, 3, 4]);
+    /// set.retain_mut(|x| {
+    ///     *x = 10 - *x;
+    ///     *x % 2 == 0
+    /// });
+    ///
+    /// assert_eq!(set.iter().copied().collect::<Vec<_>>(), [6, 8]);
+    /// ```
+    pub fn retain_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        self.storage.retain_mut(f);
+        self.orderer.sort_slice(&mut self.storage);
+    }
+
+    /// Removes all elements where the provided predicate returns `true`, returning the
+    /// removed elements in sorted order. The remaining elements are left sorted.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let mut set = OrdBySet::fully_ordered().with_items([4, 1, 3, 2]);
+    /// let evens = set.extract_if(|x| x % 2 == 0);
+    ///
+    /// assert_eq!(evens, [2, 4]);
+    /// assert_eq!(set.iter().copied().collect::<Vec<_>>(), [1, 3]);
+    /// ```
+    pub fn extract_if<F>(&mut self, mut pred: F) -> Vec<T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let (extracted, kept) = core::mem::take(&mut self.storage)
+            .into_iter()
+            .partition(|item| pred(item));
+        self.storage = kept;
+
+        extracted
+    }
+
+    /// Removes all but the first item of each group of equivelant items, leaving a single
+    /// representative of each group.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let mut set = OrdBySet::fully_ordered().with_items([1, 1, 2, 3, 3]);
+    /// set.dedup();
+    ///
+    /// assert_eq!(set.len(), 3);
+    /// assert_eq!(set.count(&3), 1);
+    /// ```
+    pub fn dedup(&mut self) {
+        let orderer = &self.orderer;
+        self.storage
+            .dedup_by(|item, kept| orderer.order_of(item, kept).is_eq());
+    }
+
+    /// Removes all but the last item of each group of equivelant items, leaving a single
+    /// representative of each group.
+    pub fn dedup_keep_last(&mut self) {
+        let orderer = &self.orderer;
+        self.storage.dedup_by(|item, kept| {
+            let is_eq = orderer.order_of(item, kept).is_eq();
+            if is_eq {
+                // move the later item into the kept slot so the earlier one is removed
+                core::mem::swap(item, kept);
+            }
+
+            is_eq
+        });
+    }
+
+    /// Removes all but the first of consecutive items which `same` considers the same,
+    /// following the semantics of [`Vec::dedup_by`]. `same` is passed the item being
+    /// considered for removal followed by the preceding item which is being kept.
+    ///
+    /// As only consecutive items are compared, `same` should only consider items the same
+    /// if they are also equivelant according to the orderer, otherwise items which `same`
+    /// considers the same may not be adjacent. If `same` modifies items such that their
+    /// ordering changes, the state of the set is unspecified until
+    /// [`resort`](OrdBySet::resort) is called.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let by_len = |left: &&str, right: &&str| left.len().cmp(&right.len());
+    /// let mut set = OrdBySet::new_with_order(by_len).with_items(["ab", "AB", "cd", "e"]);
+    ///
+    /// set.dedup_by(|item, kept| item.eq_ignore_ascii_case(kept));
+    /// assert_eq!(set.as_slice(), ["e", "ab", "cd"]);
+    /// ```
+    pub fn dedup_by<F>(&mut self, same: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        self.storage.dedup_by(same);
+    }
+
+    /// Get the range of indices, in sorted order, of the group of items equivelant to the
+    /// provided item, or `None` if no equivelant items are present. No sorting order
+    /// within the range is guaranteed.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let set = OrdBySet::fully_ordered().with_items([1, 2, 2, 3]);
+    ///
+    /// assert_eq!(set.group_range(&2), Some(1..3));
+    /// assert_eq!(set.group_range(&4), None);
+    /// ```
+    pub fn group_range(&self, item: &T) -> Option<Range<usize>> {
+        self.get_index_range_of(item)
+    }
+
+    /// Get a slice of all equivelant items. No sorting order within is guaranteed.
+    ///
+    /// Returns `None` if no matching items were found in the set.
+    pub fn get(&self, item: &T) -> Option<&[T]> {
+        Some(&self.storage[self.get_index_range_of(item)?])
+    }
+
+    /// Get a slice of all items equivelant to a key of a different type, using `cmp` to
+    /// compare items of the set to the key. No sorting order within is guaranteed.
+    ///
+    /// `cmp` must order items consistently with the orderer of the set, such that the
+    /// items it considers less than, equal to, and greater than the key are each contiguous
+    /// and in that order.
+    ///
+    /// Returns `None` if no matching items were found in the set.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let ordering_fn = |left: &&str, right: &&str| left[..5].cmp(&right[..5]);
+    ///
+    /// let set = OrdBySet::new_with_order(ordering_fn)
+    ///     .with_items(["00001_foo", "00001_bar", "00002_foo"]);
+    ///
+    /// let id_1_subset = set.get_by(&1, |item, id| item[..5].parse::<u32>().unwrap().cmp(id));
+    /// assert_eq!(id_1_subset.unwrap().len(), 2);
+    /// ```
+    pub fn get_by<Q, F>(&self, key: &Q, cmp: F) -> Option<&[T]>
+    where
+        F: Fn(&T, &Q) -> Ordering,
+    {
+        self.find_group_by(|probe| cmp(probe, key))
+    }
+
+    /// Get a slice of all items for which `cmp` returns `Ordering::Equal`, where `cmp`
+    /// returns how each item of the set is ordered relative to the group being searched
+    /// for. No sorting order within is guaranteed.
+    ///
+    /// `cmp` must be monotonic over the sorted items of the set, such that the items it
+    /// returns `Ordering::Less`, `Ordering::Equal`, and `Ordering::Greater` for are each
+    /// contiguous and in that order. Otherwise the returned group is unspecified.
+    ///
+    /// Returns `None` if no matching items were found in the set.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let set = OrdBySet::fully_ordered().with_items([1, 12, 15, 17, 25]);
+    ///
+    /// let tens = set.find_group_by(|item| (item / 10).cmp(&1));
+    /// assert_eq!(tens, Some(&[12, 15, 17][..]));
+    /// ```
+    pub fn find_group_by<F>(&self, cmp: F) -> Option<&[T]>
+    where
+        F: Fn(&T) -> Ordering,
+    {
+        Some(&self.storage[self.index_range_by(cmp)?])
+    }
+
+    /// Get the first item of the group of equivelant items, that being the first item of
+    /// the slice returned by [`get`](OrdBySet::get).
+    pub fn get_first(&self, item: &T) -> Option<&T> {
+        let range = self.get_index_range_of(item)?;
+
+        self.storage.get(range.start)
+    }
+
+    /// Get a slice of all equivelant items. No sorting order within is guaranteed
+    ///
+    /// **Note:** the set is re-sorted when this [`SliceGuard`] is dropped. If it is
+    /// instead leaked, such as via `mem::forget`, the state of the `OrdBySet` is
+    /// unspecified until [`resort`](OrdBySet::resort) is called.
+    pub fn get_mut(&mut self, item: &T) -> Option<SliceGuard<'_, T, Orderer>> {
+        let range = self.get_index_range_of(item)?;
+
+        Some(SliceGuard::new(self, range))
+    }
+
+    /// Get a mutable reference to the first item of the group of equivelant items, that
+    /// being the same item returned by [`get_first`](OrdBySet::get_first).
+    ///
+    /// **Note:** the set is re-sorted when this [`MutRefGuard`] is dropped. If it is
+    /// instead leaked, such as via `mem::forget`, the state of the `OrdBySet` is
+    /// unspecified until [`resort`](OrdBySet::resort) is called.
+    pub fn get_first_mut(&mut self, item: &T) -> Option<MutRefGuard<'_, T, Orderer>> {
+        let range = self.get_index_range_of(item)?;
+
+        Some(MutRefGuard::new(self, range.start))
+    }
+
+    /// Get the smallest item in the set according to the orderer, or `None` if the set
+    /// is empty.
+    pub fn first(&self) -> Option<&T> {
+        self.storage.first()
+    }
+
+    /// Get the largest item in the set according to the orderer, or `None` if the set
+    /// is empty.
+    pub fn last(&self) -> Option<&T> {
+        self.storage.last()
+    }
+
+    /// Get the item at the given position in sorted order, or `None` if `index` is out of
+    /// bounds. That is to say, `nth(0)` is the same as [`first`](OrdBySet::first) and
+    /// `nth(len() - 1)` is the same as [`last`](OrdBySet::last).
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let set = OrdBySet::fully_ordered().with_items([5, 1, 4, 2, 3]);
+    ///
+    /// let median = set.nth(set.len() / 2);
+    /// assert_eq!(median, Some(&3));
+    /// assert_eq!(set.nth(5), None);
+    /// ```
+    pub fn nth(&self, index: usize) -> Option<&T> {
+        self.storage.get(index)
+    }
+
+    /// Get a mutable reference to the item at the given position in sorted order, or `None`
+    /// if `index` is out of bounds. See [`nth`](OrdBySet::nth).
+    ///
+    /// **Note:** the set is re-sorted when this [`MutRefGuard`] is dropped. If it is
+    /// instead leaked, such as via `mem::forget`, the state of the `OrdBySet` is
+    /// unspecified until [`resort`](OrdBySet::resort) is called.
+    pub fn nth_mut(&mut self, index: usize) -> Option<MutRefGuard<'_, T, Orderer>> {
+        (index < self.storage.len()).then(move || MutRefGuard::new(self, index))
+    }
+
+    /// Removes and returns the smallest item in the set according to the orderer, or
+    /// `None` if the set is empty.
+    ///
+    /// **Note:** due to being backed by a `Vec`, this requires shifting every remaining
+    /// item and is thus O(n). If items are only ever popped from the front, consider
+    /// reversing the orderer and using [`pop_last`](OrdBySet::pop_last) instead.
+    pub fn pop_first(&mut self) -> Option<T> {
+        (!self.storage.is_empty()).then(|| self.storage.remove(0))
+    }
+
+    /// Removes and returns the largest item in the set according to the orderer, or
+    /// `None` if the set is empty.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let mut set = OrdBySet::fully_ordered().with_items([2, 3, 1]);
+    ///
+    /// assert_eq!(set.pop_last(), Some(3));
+    /// assert_eq!(set.pop_first(), Some(1));
+    /// assert_eq!(set.pop_last(), Some(2));
+    /// assert_eq!(set.pop_last(), None);
+    /// ```
+    pub fn pop_last(&mut self) -> Option<T> {
+        self.storage.pop()
+    }
+
+    /// Check if an equivelant item is contained in the set
+    pub fn contains(&self, item: &T) -> bool {
+        !self.storage.is_empty() && self.search(item).is_ok()
+    }
+
+    /// Check if every provided item has an equivelant item contained in the set. Stops
+    /// checking at the first item which is not contained, and returns `true` if no items
+    /// are provided.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let set = OrdBySet::fully_ordered().with_items([1, 2, 3]);
+    ///
+    /// assert!(set.contains_all(&[1, 3]));
+    /// assert!(!set.contains_all(&[1, 4]));
+    /// assert!(set.contains_any(&[1, 4]));
+    /// assert!(!set.contains_any(&[4, 5]));
+    /// ```
+    pub fn contains_all<'a, I>(&self, items: I) -> bool
+    where
+        I: IntoIterator<Item = &'a T>,
+        T: 'a,
+    {
+        items.into_iter().all(|item| self.contains(item))
+    }
+
+    /// Check if any of the provided items has an equivelant item contained in the set.
+    /// Stops checking at the first item which is contained, and returns `false` if no
+    /// items are provided.
+    pub fn contains_any<'a, I>(&self, items: I) -> bool
+    where
+        I: IntoIterator<Item = &'a T>,
+        T: 'a,
+    {
+        items.into_iter().any(|item| self.contains(item))
+    }
+
+    /// Check the number of equivelant items contained in the set
+    pub fn count(&self, item: &T) -> usize {
+        self.get_index_range_of(item)
+            .map(|range| range.len())
+            .unwrap_or(0)
+    }
+
+    /// Returns an iterator over all of the elements in no specified order
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.storage.iter()
+    }
+
+    /// Returns an iterator over all of the elements in ascending order according to the
+    /// orderer, from the smallest item to the largest. Unlike [`iter`](OrdBySet::iter),
+    /// this order is guaranteed, although the relative order of items within each group
+    /// of equivelant items is not specified.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let set = OrdBySet::fully_ordered().with_items([2, 3, 1]);
+    /// assert_eq!(set.iter_sorted().copied().collect::<Vec<_>>(), [1, 2, 3]);
+    /// ```
+    pub fn iter_sorted(&self) -> impl Iterator<Item = &T> + '_ {
+        self.storage.iter()
+    }
+
+    /// Returns an iterator over all of the elements in descending order according to the
+    /// orderer, from the largest item to the smallest. The relative order of items within
+    /// each group of equivelant items is not specified.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let set = OrdBySet::fully_ordered().with_items([2, 3, 1]);
+    /// assert_eq!(set.iter_rev().copied().collect::<Vec<_>>(), [3, 2, 1]);
+    /// ```
+    pub fn iter_rev(&self) -> impl Iterator<Item = &T> + '_ {
+        self.storage.iter().rev()
+    }
+
+    /// Returns an iterator over all of the elements in no specified order such that
+    /// each value can be modified.
+    ///
+    /// **Note:** the set is not re-sorted afterwards, so the state of the `OrdBySet` is
+    /// unspecified if the ordering of any item is changed until
+    /// [`resort`](OrdBySet::resort) is called.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> + '_ {
+        self.storage.iter_mut()
+    }
+
+    /// Get a mutable slice of every item in the set, guarded such that the set is re-sorted
+    /// once the [`SliceGuard`] is dropped. This is the preferred way to mutably iterate
+    /// over the set if the ordering of items might be changed.
+    ///
+    /// **Note:** the set is re-sorted when this [`SliceGuard`] is dropped. If it is
+    /// instead leaked, such as via `mem::forget`, the state of the `OrdBySet` is
+    /// unspecified until [`resort`](OrdBySet::resort) is called.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let mut set = OrdBySet::fully_ordered().with_items([1, 2, 3]);
+    /// set.iter_mut_guarded()
+    ///     .iter_mut()
+    ///     .for_each(|item| *item = 10 - *item);
+    ///
+    /// assert_eq!(set.as_slice(), [7, 8, 9]);
+    /// ```
+    pub fn iter_mut_guarded(&mut self) -> SliceGuard<'_, T, Orderer> {
+        let range = 0..self.storage.len();
+
+        SliceGuard::new(self, range)
+    }
+
+    /// Get a slice of every item in the set. The slice is sorted according to the
+    /// orderer, meaning each group of equivelant items is contiguous, although no sorting
+    /// order within each group is guaranteed.
+    pub fn as_slice(&self) -> &[T] {
+        &self.storage
+    }
+
+    /// Consumes the set, returning its items as a `Vec` sorted according to the orderer
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let set = OrdBySet::fully_ordered().with_items([3, 1, 2]);
+    /// assert_eq!(set.into_vec(), [1, 2, 3]);
+    /// ```
+    pub fn into_vec(self) -> Vec<T> {
+        self.storage
+    }
+
+    /// Consumes the set, re-sorting its items into a new set using the provided orderer.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let set = OrdBySet::fully_ordered().with_items(["bb", "a", "ccc"]);
+    /// assert_eq!(set.as_slice(), ["a", "bb", "ccc"]);
+    ///
+    /// let longest_first = |left: &&str, right: &&str| right.len().cmp(&left.len());
+    /// let set = set.with_orderer(longest_first);
+    /// assert_eq!(set.as_slice(), ["ccc", "bb", "a"]);
+    /// ```
+    pub fn with_orderer<NewOrderer>(self, orderer: NewOrderer) -> OrdBySet<T, NewOrderer>
+    where
+        NewOrderer: Order<T>,
+    {
+        let mut set = OrdBySet::new_with_order(orderer).with_items(self.storage);
+        set.shrink_policy = self.shrink_policy;
+
+        set
+    }
+
+    /// Consumes the set, transforming each item and sorting the results into a new set
+    /// using the provided orderer.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::{FullOrd, OrdBySet};
+    ///
+    /// let set = OrdBySet::fully_ordered().with_items(["10", "9", "100"]);
+    /// let numbers = set.map_into(FullOrd, |item| item.parse::<u32>().unwrap());
+    ///
+    /// assert_eq!(numbers.into_vec(), [9, 10, 100]);
+    /// ```
+    pub fn map_into<U, NewOrderer, F>(self, orderer: NewOrderer, f: F) -> OrdBySet<U, NewOrderer>
+    where
+        NewOrderer: Order<U>,
+        F: FnMut(T) -> U,
+    {
+        OrdBySet::new_with_order(orderer)
+            .with_items(self.storage.into_iter().map(f).collect::<Vec<_>>())
+    }
+
+    /// Consumes the set, transforming each item and sorting the results into a new set
+    /// using the provided orderer. Items for which `f` returns `None` are discarded.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::{FullOrd, OrdBySet};
+    ///
+    /// let set = OrdBySet::fully_ordered().with_items(["10", "nine", "100", "8"]);
+    /// let numbers = set.filter_map_into(FullOrd, |item| item.parse::<u32>().ok());
+    ///
+    /// assert_eq!(numbers.into_vec(), [8, 10, 100]);
+    /// ```
+    pub fn filter_map_into<U, NewOrderer, F>(
+        self,
+        orderer: NewOrderer,
+        f: F,
+    ) -> OrdBySet<U, NewOrderer>
+    where
+        NewOrderer: Order<U>,
+        F: FnMut(T) -> Option<U>,
+    {
+        OrdBySet::new_with_order(orderer)
+            .with_items(self.storage.into_iter().filter_map(f).collect::<Vec<_>>())
+    }
+
+    /// Get a [`Cursor`] pointing to the first item of the set, allowing for traversal of
+    /// the set in sorted order.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let set = OrdBySet::fully_ordered().with_items([2, 1, 3]);
+    /// let mut cursor = set.cursor();
+    ///
+    /// assert_eq!(cursor.current(), Some(&1));
+    /// assert_eq!(cursor.peek_prev(), None);
+    ///
+    /// cursor.move_next();
+    /// assert_eq!(cursor.current(), Some(&2));
+    /// assert_eq!(cursor.peek_next(), Some(&3));
+    /// ```
+    pub fn cursor(&self) -> Cursor<'_, T, Orderer> {
+        Cursor(self, 0)
+    }
+
+    /// Get a [`CursorMut`] pointing to the first item of the set, allowing for traversal
+    /// of the set in sorted order while modifying items.
+    ///
+    /// **Note:** the set is re-sorted when this [`CursorMut`] is dropped. If it is
+    /// instead leaked, such as via `mem::forget`, the state of the `OrdBySet` is
+    /// unspecified until [`resort`](OrdBySet::resort) is called.
+    pub fn cursor_mut(&mut self) -> CursorMut<'_, T, Orderer> {
+        CursorMut::new(self)
+    }
+
+    /// Returns an iterator over each group of equivelant items, in order, with each group
+    /// being yielded as a slice. No sorting order within each group is guaranteed.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let set = OrdBySet::fully_ordered().with_items([3, 1, 3, 2, 1, 3]);
+    /// let mut groups = set.groups();
+    ///
+    /// assert_eq!(groups.next(), Some(&[1, 1][..]));
+    /// assert_eq!(groups.next(), Some(&[2][..]));
+    /// assert_eq!(groups.next(), Some(&[3, 3, 3][..]));
+    /// assert_eq!(groups.next(), None);
+    /// ```
+    pub fn groups(&self) -> impl Iterator<Item = &[T]> + '_ {
+        let mut start = 0;
+
+        core::iter::from_fn(move || {
+            (start < self.storage.len()).then(|| {
+                let end = self.group_end(start);
+                let group = &self.storage[start..end];
+                start = end;
+
+                group
+            })
+        })
+    }
+
+    /// Returns a mutable traversal over each group of equivelant items, in order, with each
+    /// group being yielded as a [`SliceGuard`] which only re-sorts its own group.
+    ///
+    /// **Note:** changing the items of a group such that they are no longer equivelant to
+    /// the rest of the group causes the whole set to be re-sorted, after which the groups
+    /// which are yielded are unspecified.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let by_len = |left: &String, right: &String| left.len().cmp(&right.len());
+    /// let mut set = OrdBySet::new_with_order(by_len)
+    ///     .with_items(["a".to_owned(), "bb".to_owned(), "c".to_owned()]);
+    ///
+    /// let mut groups = set.groups_mut();
+    /// while let Some(mut group) = groups.next_group() {
+    ///     group.iter_mut().for_each(|item| item.make_ascii_uppercase());
+    /// }
+    ///
+    /// assert_eq!(set.as_slice(), ["A", "C", "BB"]);
+    /// ```
+    pub fn groups_mut(&mut self) -> GroupsMut<'_, T, Orderer> {
+        GroupsMut(self, 0)
+    }
+
+    /// Returns an iterator over the items of the set in chunks of `size` items, in sorted
+    /// order. The last chunk may have fewer than `size` items. Unlike
+    /// [`groups`](OrdBySet::groups), chunks do not align to the boundaries of groups of
+    /// equivelant items.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let set = OrdBySet::fully_ordered().with_items([5, 1, 4, 2, 3]);
+    /// let mut chunks = set.chunks(2);
+    ///
+    /// assert_eq!(chunks.next(), Some(&[1, 2][..]));
+    /// assert_eq!(chunks.next(), Some(&[3, 4][..]));
+    /// assert_eq!(chunks.next(), Some(&[5][..]));
+    /// assert_eq!(chunks.next(), None);
+    /// ```
+    pub fn chunks(&self, size: usize) -> impl Iterator<Item = &[T]> + '_ {
+        self.storage.chunks(size)
+    }
+
+    /// Returns an iterator over every contiguous window of `size` items in the set, in
+    /// sorted order. As the set is sorted, each window contains neighboring items, making
+    /// this useful for comparing each item to those around it. If the set has fewer than
+    /// `size` items, no windows are yielded.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let set = OrdBySet::fully_ordered().with_items([10, 1, 3, 6]);
+    /// let largest_gap = set.windows(2).map(|pair| pair[1] - pair[0]).max();
+    ///
+    /// assert_eq!(largest_gap, Some(4));
+    /// ```
+    pub fn windows(&self, size: usize) -> impl Iterator<Item = &[T]> + '_ {
+        self.storage.windows(size)
+    }
+
+    /// Get the group of items equivelant to the smallest item in the set, or `None` if the
+    /// set is empty. This is the same as the first group yielded by
+    /// [`groups`](OrdBySet::groups).
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let set = OrdBySet::fully_ordered().with_items([3, 1, 3, 2, 1, 3]);
+    ///
+    /// assert_eq!(set.first_group(), Some(&[1, 1][..]));
+    /// assert_eq!(set.last_group(), Some(&[3, 3, 3][..]));
+    /// ```
+    pub fn first_group(&self) -> Option<&[T]> {
+        (!self.storage.is_empty()).then(|| &self.storage[..self.group_end(0)])
+    }
+
+    /// Get the group of items equivelant to the largest item in the set, or `None` if the
+    /// set is empty. This is the same as the last group yielded by
+    /// [`groups`](OrdBySet::groups).
+    pub fn last_group(&self) -> Option<&[T]> {
+        let last = self.storage.len().checked_sub(1)?;
+
+        Some(&self.storage[self.group_start(last)..])
+    }
+
+    /// Removes and returns the group of items equivelant to the smallest item in the set,
+    /// returning an empty `Vec` if the set is empty.
+    ///
+    /// **Note:** due to being backed by a `Vec`, this requires shifting every remaining
+    /// item. If groups are only ever popped from the front, consider reversing the orderer
+    /// and using [`pop_last_group`](OrdBySet::pop_last_group) instead.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let mut set = OrdBySet::fully_ordered().with_items([3, 1, 3, 2, 1, 3]);
+    ///
+    /// assert_eq!(set.pop_first_group(), [1, 1]);
+    /// assert_eq!(set.pop_last_group(), [3, 3, 3]);
+    /// assert_eq!(set.pop_last_group(), [2]);
+    /// assert!(set.pop_first_group().is_empty());
+    /// ```
+    pub fn pop_first_group(&mut self) -> Vec<T> {
+        if self.storage.is_empty() {
+            return Vec::new();
+        }
+
+        let end = self.group_end(0);
+        self.storage.drain(..end).collect()
+    }
+
+    /// Removes and returns the group of items equivelant to the largest item in the set,
+    /// returning an empty `Vec` if the set is empty.
+    pub fn pop_last_group(&mut self) -> Vec<T> {
+        match self.storage.len().checked_sub(1) {
+            Some(last) => {
+                let start = self.group_start(last);
+                self.storage.split_off(start)
+            }
+            None => Vec::new(),
+        }
+    }
+
+    /// Get the number of distinct groups of equivelant items in the set
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let set = OrdBySet::fully_ordered().with_items([1, 1, 2, 3, 3, 3]);
+    /// assert_eq!(set.group_count(), 3);
+    /// ```
+    pub fn group_count(&self) -> usize {
+        self.groups().count()
+    }
+
+    /// Get the number of items in each group of equivelant items, in order, alongside the
+    /// first item of each group.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let set = OrdBySet::fully_ordered().with_items([1, 1, 2, 3, 3, 3]);
+    /// assert_eq!(set.frequencies(), [(&1, 2), (&2, 1), (&3, 3)]);
+    /// ```
+    pub fn frequencies(&self) -> Vec<(&T, usize)> {
+        self.groups()
+            .map(|group| (&group[0], group.len()))
+            .collect()
+    }
+
+    /// Folds each group of equivelant items into a single value, returning one value per
+    /// group. Each group starts from a value returned by `init_per_group`, and groups are
+    /// folded in ascending order.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let by_len = |left: &&str, right: &&str| left.len().cmp(&right.len());
+    /// let set = OrdBySet::new_with_order(by_len).with_items(["bb", "a", "cc", "b"]);
+    ///
+    /// let joined = set.fold_groups(String::new, |acc, item| acc + item);
+    /// assert_eq!(joined, ["ab", "bbcc"]);
+    /// ```
+    pub fn fold_groups<A, I, F>(&self, init_per_group: I, mut f: F) -> Vec<A>
+    where
+        I: Fn() -> A,
+        F: FnMut(A, &T) -> A,
+    {
+        self.groups()
+            .map(|group| group.iter().fold(init_per_group(), &mut f))
+            .collect()
+    }
+
+    /// Retains only the groups of equivelant items specified by the predicate, removing
+    /// every item of each group where the predicate returns `false`. Each group is passed
+    /// to the predicate as a slice, in order.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let mut set = OrdBySet::fully_ordered().with_items([1, 2, 2, 3]);
+    /// set.retain_groups(|group| group.len() > 1);
+    ///
+    /// assert_eq!(set.as_slice(), [2, 2]);
+    /// ```
+    pub fn retain_groups<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&[T]) -> bool,
+    {
+        let mut group_end = 0;
+        let mut keep_group = false;
+
+        self.retain_indices(0..self.storage.len(), |set, i| {
+            if i == group_end {
+                group_end = set.group_end(i);
+                keep_group = f(&set.storage[i..group_end]);
+            }
+
+            keep_group
+        });
+    }
+
+    /// Removes items from each group of equivelant items with more than `max` items,
+    /// keeping only the first `max` items of each group. Returns the number of items
+    /// removed.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let mut set = OrdBySet::fully_ordered().with_items([1, 1, 1, 1, 2]);
+    ///
+    /// assert_eq!(set.cap_group_size(2), 2);
+    /// assert_eq!(set.as_slice(), [1, 1, 2]);
+    /// ```
+    pub fn cap_group_size(&mut self, max: usize) -> usize {
+        let mut group_start = 0;
+        let mut group_end = 0;
+
+        self.retain_indices(0..self.storage.len(), |set, i| {
+            if i == group_end {
+                group_start = i;
+                group_end = set.group_end(i);
+            }
+
+            i - group_start < max
+        })
+    }
+
+    /// Removes every group of equivelant items for which the predicate returns `true`,
+    /// returning the removed groups in ascending order. Each group is passed to the
+    /// predicate as a slice, in order. This is the counterpart to
+    /// [`retain_groups`](OrdBySet::retain_groups) which returns the removed items.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let mut set = OrdBySet::fully_ordered().with_items([1, 2, 2, 3, 3, 3]);
+    /// let extracted = set.extract_groups(|group| group.len() >= 2);
+    ///
+    /// assert_eq!(extracted, [vec![2, 2], vec![3, 3, 3]]);
+    /// assert_eq!(set.as_slice(), [1]);
+    /// ```
+    pub fn extract_groups<F>(&mut self, mut f: F) -> Vec<Vec<T>>
+    where
+        F: FnMut(&[T]) -> bool,
+    {
+        let decisions = self
+            .groups()
+            .map(|group| (group.len(), f(group)))
+            .collect::<Vec<_>>();
+
+        let mut items = core::mem::take(&mut self.storage).into_iter();
+        let mut extracted = Vec::new();
+
+        for (len, extract) in decisions {
+            let group = items.by_ref().take(len);
+
+            if extract {
+                extracted.push(group.collect());
+            } else {
+                self.storage.extend(group);
+            }
+        }
+
+        extracted
+    }
+
+    /// Replaces the contents of the set with the contents of a `Vec`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let set: OrdBySet<u64> = OrdBySet::new().with_items([3, 1, 3, 2]);
+    /// assert_eq!(set.count(&3), 2);
+    /// ```
+    pub fn with_items<Items: Into<Vec<T>>>(self, items: Items) -> Self {
+        let mut storage = items.into();
+        self.orderer.sort_slice(&mut storage);
+
+        let set = Self { storage, ..self };
+        set.debug_assert_sorted();
+
+        set
+    }
+
+    /// Get the number of items in the set
+    pub fn len(&self) -> usize {
+        self.storage.len()
+    }
+
+    /// Returns the number of elements the set can hold without reallocating
+    pub fn capacity(&self) -> usize {
+        self.storage.capacity()
+    }
+
+    /// Reserves capacity for at least `additional` more items to be inserted. See
+    /// [`Vec::reserve`] for more details.
+    pub fn reserve(&mut self, additional: usize) {
+        self.storage.reserve(additional);
+    }
+
+    /// Reserves the minimum capacity for exactly `additional` more items to be inserted.
+    /// See [`Vec::reserve_exact`] for more details.
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.storage.reserve_exact(additional);
+    }
+
+    /// Shrinks the capacity of the set as much as possible. See [`Vec::shrink_to_fit`]
+    /// for more details.
+    pub fn shrink_to_fit(&mut self) {
+        self.storage.shrink_to_fit();
+    }
+
+    /// Shrinks the capacity of the set with a lower bound. See [`Vec::shrink_to`] for
+    /// more details.
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.storage.shrink_to(min_capacity);
+    }
+
+    /// Sets the policy for automatically shrinking the capacity of the set after items are
+    /// removed via [`remove_all`](OrdBySet::remove_all), [`drain`](OrdBySet::drain),
+    /// [`remove_range`](OrdBySet::remove_range), or
+    /// [`drain_range`](OrdBySet::drain_range). Defaults to [`ShrinkPolicy::Never`].
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::{OrdBySet, ShrinkPolicy};
+    ///
+    /// let mut set = OrdBySet::fully_ordered().with_items((0..100).collect::<Vec<_>>());
+    /// set.set_shrink_policy(ShrinkPolicy::WhenQuarterFull);
+    ///
+    /// set.remove_range(&0, &89);
+    /// assert!(set.capacity() < 25);
+    /// ```
+    pub fn set_shrink_policy(&mut self, policy: ShrinkPolicy) {
+        self.shrink_policy = policy;
+    }
+
+    /// Shrinks the capacity of the set to fit its items if the shrink policy calls for it
+    pub(crate) fn apply_shrink_policy(&mut self) {
+        if self
+            .shrink_policy
+            .should_shrink(self.storage.len(), self.storage.capacity())
+        {
+            self.storage.shrink_to_fit();
+        }
+    }
+
+    /// Remove all items in the set. This has no effect on the allocated capacity of the
+    /// set, see [`shrink_to_fit`](OrdBySet::shrink_to_fit) to free the allocated memory.
+    pub fn clear(&mut self) {
+        self.storage.truncate(0);
+    }
+
+    /// Shortens the set, keeping the first `len` items in sorted order (the smallest items
+    /// according to the orderer) and dropping the rest. Has no effect if `len` is greater
+    /// than or equal to the number of items in the set, and has no effect on the allocated
+    /// capacity of the set.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::{FullOrd, OrdBySet};
+    ///
+    /// let mut set = OrdBySet::new_with_order(FullOrd.reverse()).with_items([3, 5, 1, 4]);
+    /// set.truncate(2);
+    ///
+    /// assert_eq!(set.as_slice(), [5, 4]);
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        self.storage.truncate(len);
+    }
+
+    /// Keeps at most the `n` smallest items in the set according to the orderer, dropping
+    /// the rest. This is the same as [`truncate`](OrdBySet::truncate).
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let mut set = OrdBySet::fully_ordered().with_items([3, 5, 1, 4]);
+    /// set.keep_smallest(3);
+    /// assert_eq!(set.as_slice(), [1, 3, 4]);
+    ///
+    /// set.keep_largest(2);
+    /// assert_eq!(set.as_slice(), [3, 4]);
+    /// ```
+    pub fn keep_smallest(&mut self, n: usize) {
+        self.truncate(n);
+    }
+
+    /// Keeps at most the `n` largest items in the set according to the orderer, dropping
+    /// the rest.
+    ///
+    /// **Note:** due to being backed by a `Vec`, this requires shifting every remaining
+    /// item. If this is done frequently, consider reversing the orderer and using
+    /// [`keep_smallest`](OrdBySet::keep_smallest) instead.
+    pub fn keep_largest(&mut self, n: usize) {
+        let excess = self.storage.len().saturating_sub(n);
+
+        // drop to ensure elements are removed immediately.
+        drop(self.storage.drain(..excess));
+    }
+
+    /// Checks if there are any items inside the set
+    pub fn is_empty(&self) -> bool {
+        self.storage.is_empty()
+    }
+
+    /// Checks that every item in the set is ordered before or equal to the item which
+    /// follows it, according to the orderer. This should always be `true` unless the
+    /// [`Order`] implementation violates its contract or the set was constructed from
+    /// unsorted items via [`from_sorted_unchecked`](OrdBySet::from_sorted_unchecked).
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::{FullOrd, OrdBySet};
+    ///
+    /// assert!(OrdBySet::fully_ordered().with_items([3, 1, 2]).is_sorted());
+    /// assert!(!OrdBySet::from_sorted_unchecked(FullOrd, vec![3, 1, 2]).is_sorted());
+    /// ```
+    pub fn is_sorted(&self) -> bool {
+        self.storage
+            .windows(2)
+            .all(|pair| self.orderer.order_of(&pair[0], &pair[1]).is_le())
+    }
+
+    /// Re-sorts every item in the set according to the orderer, restoring the ordering of
+    /// the set if it was broken, such as by leaking a guard which modified items, changing
+    /// the ordering of items via [`iter_mut`](OrdBySet::iter_mut), or constructing the set
+    /// from unsorted items via [`from_sorted_unchecked`](OrdBySet::from_sorted_unchecked).
+    ///
+    /// When changing the ordering of many items at once, modifying them all via `iter_mut`
+    /// and then calling `resort` once is more efficient than using a guard for each item.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let mut set = OrdBySet::fully_ordered().with_items([1, 2, 3]);
+    /// set.iter_mut().for_each(|item| *item = 10 - *item);
+    /// assert!(!set.is_sorted());
+    ///
+    /// set.resort();
+    /// assert_eq!(set.as_slice(), [7, 8, 9]);
+    /// ```
+    pub fn resort(&mut self) {
+        self.orderer.sort_slice(&mut self.storage);
+    }
+
+    /// Re-sorts the set after only the items within `range` may have been modified
+    pub(crate) fn resort_range(&mut self, range: Range<usize>) {
+        let OrdBySet {
+            storage, orderer, ..
+        } = self;
+        let Range { start, end } = range;
+
+        // only the given range can have been modified, so sort it on its own and then
+        // make sure it still fits between its neighbors before falling back to a full sort
+        orderer.sort_slice(&mut storage[start..end]);
+
+        let in_order = |left: usize, right: usize| {
+            left >= right || orderer.order_of(&storage[left], &storage[right]).is_le()
+        };
+        let fits_start = start == 0 || in_order(start - 1, start);
+        let fits_end = end >= storage.len() || in_order(end - 1, end);
+
+        if !(fits_start && fits_end) {
+            orderer.sort_slice(storage);
+        }
+
+        self.debug_assert_sorted();
+    }
+
+    /// Panics if any item is ordered after the item which follows it, which can only
+    /// happen if the [`Order`] implementation violates its contract or unsorted items were
+    /// passed to a method which trusts the caller to sort them. Only checked in debug
+    /// builds, so that either mistake is caught during development.
+    #[cfg(debug_assertions)]
+    pub(crate) fn debug_assert_sorted(&self) {
+        let unsorted = self
+            .storage
+            .windows(2)
+            .position(|pair| !self.orderer.order_of(&pair[0], &pair[1]).is_le());
+
+        if let Some(index) = unsorted {
+            panic!(
+                "OrdBySet is not sorted: item {} is ordered after item {}, either the \
+                 orderer violates the `Order` specification or unsorted items were \
+                 provided as sorted",
+                index,
+                index + 1
+            );
+        }
+    }
+
+    #[cfg(not(debug_assertions))]
+    #[inline(always)]
+    pub(crate) fn debug_assert_sorted(&self) {}
+
+    fn range_to_index_range(&self, low: &T, high: &T) -> Option<Range<usize>> {
+        if self.storage.is_empty() || self.orderer.order_of(low, high).is_gt() {
+            return None;
+        }
+
+        let start = self
+            .storage
+            .partition_point(|probe| self.orderer.order_of(probe, low).is_lt());
+
+        let len = self.storage[start..]
+            .partition_point(|probe| self.orderer.order_of(probe, high).is_le());
+
+        let end = start + len;
+
+        (end > start).then_some(start..end)
+    }
+
+    /// Gets a slice of all elements inclusively between two bounds. If both bounds are
+    /// equivelant this is the group of items equivelant to them.
+    ///
+    /// Returns `None` if no items are within the bounds, or if `low` is greater than
+    /// `high`.
+    pub fn range(&self, low: &T, high: &T) -> Option<&[T]> {
+        self.range_to_index_range(low, high)
+            .map(|range| &self.storage[range])
+    }
+
+    /// Gets a mutable slice of all elements between two bounds
+    pub fn range_mut(&mut self, low: &T, high: &T) -> Option<SliceGuard<'_, T, Orderer>> {
+        self.range_to_index_range(low, high)
+            .map(move |range| SliceGuard::new(self, range))
+    }
+
+    /// Get the number of elements inclusively between two bounds, returning 0 if `low` is
+    /// greater than `high`
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let set = OrdBySet::fully_ordered().with_items([1, 2, 2, 3, 5]);
+    ///
+    /// assert_eq!(set.count_range(&2, &4), 3);
+    /// assert_eq!(set.count_range(&4, &2), 0);
+    /// ```
+    pub fn count_range(&self, low: &T, high: &T) -> usize {
+        self.range_to_index_range(low, high)
+            .map(|range| range.len())
+            .unwrap_or(0)
+    }
+
+    /// Count the number of distinct groups of equivelant items inclusively between two
+    /// bounds. Returns 0 if no items are within the bounds, including if `low` is greater
+    /// than `high`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let set = OrdBySet::fully_ordered().with_items([1, 1, 2, 3, 3, 5]);
+    ///
+    /// assert_eq!(set.distinct_count_range(&1, &3), 3);
+    /// assert_eq!(set.distinct_count_range(&2, &4), 2);
+    /// assert_eq!(set.distinct_count_range(&4, &2), 0);
+    /// ```
+    pub fn distinct_count_range(&self, low: &T, high: &T) -> usize {
+        self.range(low, high)
+            .map(|items| {
+                let boundaries = items
+                    .windows(2)
+                    .filter(|pair| self.orderer.order_of(&pair[0], &pair[1]).is_ne())
+                    .count();
+
+                boundaries + 1
+            })
+            .unwrap_or(0)
+    }
+
+    /// Removes all elements inclusively between two bounds, returning the number of
+    /// elements removed.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let mut set = OrdBySet::fully_ordered().with_items([1, 2, 2, 3, 5]);
+    ///
+    /// assert_eq!(set.remove_range(&2, &4), 3);
+    /// assert_eq!(set.iter().copied().collect::<Vec<_>>(), [1, 5]);
+    /// assert_eq!(set.remove_range(&2, &4), 0);
+    /// ```
+    pub fn remove_range(&mut self, low: &T, high: &T) -> usize {
+        if let Some(range) = self.range_to_index_range(low, high) {
+            let len = range.len();
+            // drop to ensure elements are removed immediately.
+            drop(self.storage.drain(range));
+            self.apply_shrink_policy();
+
+            len
+        } else {
+            0
+        }
+    }
+
+    /// Removes all elements inclusively between two bounds, returning the removed
+    /// elements in sorted order.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let mut set = OrdBySet::fully_ordered().with_items([1, 2, 2, 3, 5]);
+    ///
+    /// assert_eq!(set.drain_range(&2, &4), [2, 2, 3]);
+    /// assert_eq!(set.iter().copied().collect::<Vec<_>>(), [1, 5]);
+    /// assert!(set.drain_range(&5, &1).is_empty());
+    /// ```
+    pub fn drain_range(&mut self, low: &T, high: &T) -> Vec<T> {
+        let drained = self
+            .range_to_index_range(low, high)
+            .map(|range| self.storage.drain(range).collect())
+            .unwrap_or_default();
+        self.apply_shrink_policy();
+
+        drained
+    }
+
+    /// Retains only the elements inclusively between two bounds for which the predicate
+    /// returns `true`. Elements outside of the bounds are left untouched, and the
+    /// predicate is not called for them.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let mut set = OrdBySet::fully_ordered().with_items([1, 2, 3, 4, 5, 6]);
+    /// set.retain_in_range(&2, &5, |item| item % 2 == 1);
+    ///
+    /// assert_eq!(set.as_slice(), [1, 3, 5, 6]);
+    /// ```
+    pub fn retain_in_range<F>(&mut self, low: &T, high: &T, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        if let Some(range) = self.range_to_index_range(low, high) {
+            self.retain_indices(range, |set, i| f(&set.storage[i]));
+        }
+    }
+
+    fn bounds_to_index_range<R: RangeBounds<T>>(&self, bounds: R) -> Range<usize> {
+        let start = match bounds.start_bound() {
+            Bound::Included(low) => self.lower_bound(low),
+            Bound::Excluded(low) => self.upper_bound(low),
+            Bound::Unbounded => 0,
+        };
+
+        let end = match bounds.end_bound() {
+            Bound::Included(high) => self.upper_bound(high),
+            Bound::Excluded(high) => self.lower_bound(high),
+            Bound::Unbounded => self.storage.len(),
+        };
+
+        start..end.max(start)
+    }
+
+    /// Gets a slice of all elements within the provided bounds. Each bound may be
+    /// inclusive, exclusive, or unbounded, and if no elements are within the bounds an
+    /// empty slice is returned.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let set = OrdBySet::fully_ordered().with_items([1, 2, 2, 3, 4]);
+    ///
+    /// assert_eq!(set.range_bounds(..), [1, 2, 2, 3, 4]);
+    /// assert_eq!(set.range_bounds(2..=2), [2, 2]);
+    /// assert_eq!(set.range_bounds(2..4), [2, 2, 3]);
+    /// assert_eq!(set.range_bounds(3..), [3, 4]);
+    /// assert!(set.range_bounds(4..2).is_empty());
+    /// ```
+    pub fn range_bounds<R: RangeBounds<T>>(&self, bounds: R) -> &[T] {
+        &self.storage[self.bounds_to_index_range(bounds)]
+    }
+}
+
+impl<T, Orderer: Order<T> + Clone> OrdBySet<T, Orderer> {
+    /// Splits the set in two at the given item, returning a new set containing every
+    /// item greater than or equal to it. Items equivelant to the provided item are moved
+    /// to the returned set, while `self` retains all lesser items. The orderer and shrink
+    /// policy of the new set are cloned from `self`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let mut set = OrdBySet::fully_ordered().with_items([1, 2, 3, 4]);
+    /// let split = set.split_off(&3);
+    ///
+    /// assert_eq!(set.iter().copied().collect::<Vec<_>>(), [1, 2]);
+    /// assert_eq!(split.iter().copied().collect::<Vec<_>>(), [3, 4]);
+    /// ```
+    pub fn split_off(&mut self, item: &T) -> Self {
+        let at = self.lower_bound(item);
+
+        self.split_off_index(at)
+    }
+
+    /// Splits the set in two at the given position in sorted order, returning a new set
+    /// containing every item from `index` onwards, while `self` retains the first `index`
+    /// (smallest) items. The orderer and shrink policy of the new set are cloned from
+    /// `self`.
+    ///
+    /// Unlike [`split_off`](OrdBySet::split_off), this may split a group of equivelant
+    /// items between the two sets.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if `index` is greater than the number of items in the set.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let mut set = OrdBySet::fully_ordered().with_items([4, 2, 3, 1]);
+    /// let split = set.split_at(1);
+    ///
+    /// assert_eq!(set.as_slice(), [1]);
+    /// assert_eq!(split.as_slice(), [2, 3, 4]);
+    /// ```
+    pub fn split_at(&mut self, index: usize) -> Self {
+        self.split_off_index(index)
+    }
+
+    /// Moves every item from index `at` onwards into a new set with the same orderer and
+    /// shrink policy, then applies the shrink policy to `self`
+    fn split_off_index(&mut self, at: usize) -> Self {
+        let mut split =
+            Self::from_sorted_unchecked(self.orderer.clone(), self.storage.split_off(at));
+        split.shrink_policy = self.shrink_policy;
+        self.apply_shrink_policy();
+
+        split
+    }
+}
+
+impl<T, Orderer: Order<T>> OrdBySet<T, Orderer>
+where
+    T: PartialEq,
+{
+    /// Searches for a specific item (based on `PartialEq`) and returns its index in sorted
+    /// order, suitable for passing to [`nth`](OrdBySet::nth).
+    ///
+    /// If multiple exist, the index of the first found is returned.
+    ///
+    /// **Note:** this method assumes that the equality of `Orderer` is a superset of
     /// `PartialEq`. That is to say that if `x == y` (`PartialEq`), then
     /// `orderer.order_of(&x, &y)` must return `Ordering::Equal`.
-    pub fn get_specific(&self, val: &T) -> Option<&T> {
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let by_len = |left: &&str, right: &&str| left.len().cmp(&right.len());
+    /// let set = OrdBySet::new_with_order(by_len).with_items(["ccc", "a", "bb", "dd"]);
+    ///
+    /// assert_eq!(set.position_of(&"dd"), Some(2));
+    /// assert_eq!(set.position_of(&"ee"), None);
+    /// ```
+    pub fn position_of(&self, val: &T) -> Option<usize> {
         let location_range = self.get_index_range_of(val)?;
         let start = location_range.start;
-        let index = self.storage[location_range].iter().position(|x| x == val)? + start;
+
+        Some(self.storage[location_range].iter().position(|x| x == val)? + start)
+    }
+
+    /// Searches for a specific item (based on `PartialEq`) and removes it, returning it
+    /// if it exists.
+    ///
+    /// If multiple exist, the first found is removed.
+    ///
+    /// **Note:** this method assumes that the equality of `Orderer` is a superset of
+    /// `PartialEq`. That is to say that if `x == y` (`PartialEq`), then
+    /// `orderer.order_of(&x, &y)` must return `Ordering::Equal`.
+    pub fn remove_specific(&mut self, val: &T) -> Option<T> {
+        let index = self.position_of(val)?;
+
+        Some(self.storage.remove(index))
+    }
+
+    /// Searches for every item equal to a specific item (based on `PartialEq`) and removes
+    /// them, returning the number of items removed.
+    ///
+    /// **Note:** this method assumes that the equality of `Orderer` is a superset of
+    /// `PartialEq`. That is to say that if `x == y` (`PartialEq`), then
+    /// `orderer.order_of(&x, &y)` must return `Ordering::Equal`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let by_len = |left: &&str, right: &&str| left.len().cmp(&right.len());
+    /// let mut set = OrdBySet::new_with_order(by_len).with_items(["aa", "bb", "aa", "c"]);
+    ///
+    /// assert_eq!(set.remove_all_specific(&"aa"), 2);
+    /// assert_eq!(set.as_slice(), ["c", "bb"]);
+    /// assert_eq!(set.remove_all_specific(&"aa"), 0);
+    /// ```
+    pub fn remove_all_specific(&mut self, val: &T) -> usize {
+        match self.get_index_range_of(val) {
+            Some(range) => self.retain_indices(range, |set, i| set.storage[i] != *val),
+            None => 0,
+        }
+    }
+
+    /// Searches for a specific item (based on `PartialEq`) and returns a reference to it.
+    ///
+    /// If multiple exist, the first found is returned.
+    ///
+    /// **Note:** this method assumes that the equality of `Orderer` is a superset of
+    /// `PartialEq`. That is to say that if `x == y` (`PartialEq`), then
+    /// `orderer.order_of(&x, &y)` must return `Ordering::Equal`.
+    pub fn get_specific(&self, val: &T) -> Option<&T> {
+        let index = self.position_of(val)?;
 
         self.storage.get(index)
     }
 
+    /// Searches for every item equal to a specific item (based on `PartialEq`) and returns
+    /// references to them, in sorted order. Returns an empty `Vec` if none are found.
+    ///
+    /// **Note:** this method assumes that the equality of `Orderer` is a superset of
+    /// `PartialEq`. That is to say that if `x == y` (`PartialEq`), then
+    /// `orderer.order_of(&x, &y)` must return `Ordering::Equal`.
+    pub fn get_all_specific(&self, val: &T) -> Vec<&T> {
+        self.get_index_range_of(val)
+            .map(|range| self.storage[range].iter().filter(|x| *x == val).collect())
+            .unwrap_or_default()
+    }
+
     /// Searches for a specific item (based on [`PartialEq`]) and returns a mutable
-    /// reference to the value.
+    /// reference to the value, guarded such that the set is re-sorted once the
+    /// [`MutRefGuard`] is dropped.
     ///
     /// If multiple exist, the first found is returned.
     ///
@@ -403,23 +2282,95 @@ where
     /// `PartialEq`. That is to say that if `x == y` (`PartialEq`), then
     /// `orderer.order_of(&x, &y)` must return `Ordering::Equal`.
     pub fn get_specific_mut(&mut self, val: &T) -> Option<MutRefGuard<'_, T, Orderer>> {
-        let location_range = self.get_index_range_of(val)?;
-        let start = location_range.start;
-        let index = self.storage[location_range].iter().position(|x| x == val)? + start;
+        let index = self.position_of(val)?;
 
-        Some(MutRefGuard(self, index))
+        Some(MutRefGuard::new(self, index))
     }
 
-    /// Returns `true` if a specific item (based on [`PartialEq`]) exists in the set.
+    /// Returns `true` if a specific item (based on [`PartialEq`]) exists in the set. Unlike
+    /// [`contains`](OrdBySet::contains), items which are only equivelant according to the
+    /// orderer are not considered.
     ///
     /// **Note:** this method assumes that the equality of `Orderer` is a superset of
     /// `PartialEq`. That is to say that if `x == y` (`PartialEq`), then
     /// `orderer.order_of(&x, &y)` must return `Ordering::Equal`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let by_len = |left: &&str, right: &&str| left.len().cmp(&right.len());
+    /// let set = OrdBySet::new_with_order(by_len).with_items(["a", "bb"]);
+    ///
+    /// assert!(set.contains(&"cc"));
+    /// assert!(!set.contains_specific(&"cc"));
+    /// assert!(set.contains_specific(&"bb"));
+    /// ```
     pub fn contains_specific(&self, val: &T) -> bool {
-        if let Some(location_range) = self.get_index_range_of(val) {
-            self.storage[location_range].iter().any(|x| x == val)
-        } else {
-            false
+        self.position_of(val).is_some()
+    }
+
+    /// Returns the number of items in the set equal to a specific item (based on
+    /// [`PartialEq`]). Unlike [`count`](OrdBySet::count), items which are only equivelant
+    /// according to the orderer are not counted.
+    ///
+    /// **Note:** this method assumes that the equality of `Orderer` is a superset of
+    /// `PartialEq`. That is to say that if `x == y` (`PartialEq`), then
+    /// `orderer.order_of(&x, &y)` must return `Ordering::Equal`.
+    pub fn count_specific(&self, val: &T) -> usize {
+        self.get_index_range_of(val)
+            .map(|range| self.storage[range].iter().filter(|x| *x == val).count())
+            .unwrap_or(0)
+    }
+
+    /// Checks if two sets are equal in the sense that they contain exactly the same
+    /// items (based on `PartialEq`), regardless of the order of items within each group
+    /// of equivelant items. This is stricter than `==`, which only checks that the sets
+    /// are equal according to the orderer.
+    ///
+    /// **Note:** this method assumes that the equality of `Orderer` is a superset of
+    /// `PartialEq`. That is to say that if `x == y` (`PartialEq`), then
+    /// `orderer.order_of(&x, &y)` must return `Ordering::Equal`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let by_len = |left: &&str, right: &&str| left.len().cmp(&right.len());
+    ///
+    /// let a = OrdBySet::new_with_order(by_len).with_items(["a", "b", "cc"]);
+    /// let b = OrdBySet::new_with_order(by_len).with_items(["b", "a", "cc"]);
+    /// let c = OrdBySet::new_with_order(by_len).with_items(["a", "a", "dd"]);
+    ///
+    /// assert!(a == c);
+    /// assert!(a.eq_exact(&b));
+    /// assert!(!a.eq_exact(&c));
+    /// ```
+    pub fn eq_exact(&self, other: &Self) -> bool {
+        if self != other {
+            return false;
+        }
+
+        // both sets are loosely equal, so their groups begin and end at the same indices
+        let mut start = 0;
+        while start < self.storage.len() {
+            let end = self.group_end(start);
+            let left = &self.storage[start..end];
+            let right = &other.storage[start..end];
+
+            let count_in = |group: &[T], item: &T| group.iter().filter(|x| *x == item).count();
+            if !left
+                .iter()
+                .all(|item| count_in(left, item) == count_in(right, item))
+            {
+                return false;
+            }
+
+            start = end;
         }
+
+        true
     }
 }
@@ -1,12 +1,20 @@
 use crate::{OrdBySet, Order};
 
 /// A drop guard that ensures the [`OrdBySet`] is properly sorted after any modifications
-/// to the underlying reference are made
+/// to the underlying reference are made. If the item is never mutably accessed, or is
+/// still ordered correctly relative to its neighbors, the set is not re-sorted.
 pub struct MutRefGuard<'set, T, Orderer: Order<T>>(
     pub(crate) &'set mut OrdBySet<T, Orderer>,
     pub(crate) usize,
+    bool,
 );
 
+impl<'set, T, Orderer: Order<T>> MutRefGuard<'set, T, Orderer> {
+    pub(crate) fn new(set: &'set mut OrdBySet<T, Orderer>, index: usize) -> Self {
+        MutRefGuard(set, index, false)
+    }
+}
+
 impl<'set, T, Orderer: Order<T>> core::ops::Deref for MutRefGuard<'set, T, Orderer> {
     type Target = T;
 
@@ -17,12 +25,38 @@ impl<'set, T, Orderer: Order<T>> core::ops::Deref for MutRefGuard<'set, T, Order
 
 impl<'set, T, Orderer: Order<T>> core::ops::DerefMut for MutRefGuard<'set, T, Orderer> {
     fn deref_mut(&mut self) -> &mut Self::Target {
+        self.2 = true;
         &mut self.0.storage[self.1]
     }
 }
 
 impl<'set, T, Orderer: Order<T>> Drop for MutRefGuard<'set, T, Orderer> {
     fn drop(&mut self) {
-        self.0.orderer.sort_slice(&mut self.0.storage);
+        // nothing can have been modified without going through `deref_mut`
+        if !self.2 {
+            return;
+        }
+
+        let OrdBySet {
+            storage, orderer, ..
+        } = &mut *self.0;
+        let index = self.1;
+        let item = &storage[index];
+
+        // only the guarded item can have been modified, so if it still fits between its
+        // neighbors the set is still sorted, otherwise move it to where it now belongs
+        if index > 0 && orderer.order_of(&storage[index - 1], item).is_gt() {
+            let new_index =
+                storage[..index].partition_point(|probe| orderer.order_of(probe, item).is_le());
+
+            storage[new_index..=index].rotate_right(1);
+        } else if index + 1 < storage.len() && orderer.order_of(item, &storage[index + 1]).is_gt() {
+            let len =
+                storage[index + 1..].partition_point(|probe| orderer.order_of(probe, item).is_lt());
+
+            storage[index..=index + len].rotate_left(1);
+        }
+
+        self.0.debug_assert_sorted();
     }
 }
@@ -65,7 +65,7 @@ use core::cmp::Ordering;
 ///
 /// * Exactly one of `a < b`, `a > b`, or `a == b` is true.
 /// * LessThan, Equals, and GreaterThan are all transitive. Which is to say that
-/// `a == b` and `b == c` implies `a == c`.
+///   `a == b` and `b == c` implies `a == c`.
 ///
 /// The easiest way to think about this is that `Order<T>` is a proper implementation of
 /// [`Ord`] for a subset of the type `T`, albeit with possibly alternate behavior to that
@@ -89,14 +89,137 @@ pub trait Order<T> {
 
     /// Takes a slice of items and sorts them using the given order
     fn sort_slice(&self, items: &mut [T]) {
-        items.sort_by(|left, right| self.order_of(&left, &right));
+        items.sort_by(|left, right| self.order_of(left, right));
+    }
+
+    /// Chains this orderer with another, such that items this orderer considers equal
+    /// are then ordered by `next`.
+    ///
+    /// If both orderers uphold the [specification](Order#specification) then so does the
+    /// resulting [`ChainedOrder`].
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::{OrdBySet, Order};
+    ///
+    /// struct Person {
+    ///     first: &'static str,
+    ///     last: &'static str,
+    /// }
+    ///
+    /// let by_last = |left: &Person, right: &Person| left.last.cmp(right.last);
+    /// let by_first = |left: &Person, right: &Person| left.first.cmp(right.first);
+    ///
+    /// let set = OrdBySet::new_with_order(by_last.then(by_first)).with_items([
+    ///     Person { first: "Bob", last: "Smith" },
+    ///     Person { first: "Alice", last: "Smith" },
+    ///     Person { first: "Carol", last: "Jones" },
+    /// ]);
+    ///
+    /// let names = set.iter().map(|person| person.first).collect::<Vec<_>>();
+    /// assert_eq!(names, ["Carol", "Alice", "Bob"]);
+    /// ```
+    fn then<Next: Order<T>>(self, next: Next) -> ChainedOrder<Self, Next>
+    where
+        Self: Sized,
+    {
+        ChainedOrder(self, next)
+    }
+
+    /// Reverses this orderer, such that items it considers greatest are ordered first.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::{OrdBySet, Order};
+    ///
+    /// let by_len = |left: &&str, right: &&str| left.len().cmp(&right.len());
+    ///
+    /// let set = OrdBySet::new_with_order(by_len.reverse()).with_items(["a", "ccc", "bb"]);
+    /// assert_eq!(set.first(), Some(&"ccc"));
+    /// ```
+    fn reverse(self) -> ReverseOrder<Self>
+    where
+        Self: Sized,
+    {
+        ReverseOrder(self)
+    }
+}
+
+/// An orderer which orders items by its first orderer, and then orders items which are
+/// equal under the first orderer by its second. See [`Order::then`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ChainedOrder<First, Second>(pub First, pub Second);
+
+impl<T, First: Order<T>, Second: Order<T>> Order<T> for ChainedOrder<First, Second> {
+    fn order_of(&self, left: &T, right: &T) -> Ordering {
+        self.0
+            .order_of(left, right)
+            .then_with(|| self.1.order_of(left, right))
+    }
+}
+
+/// An orderer which reverses the ordering of another orderer. See [`Order::reverse`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ReverseOrder<Orderer>(pub Orderer);
+
+impl<T, Orderer: Order<T>> Order<T> for ReverseOrder<Orderer> {
+    fn order_of(&self, left: &T, right: &T) -> Ordering {
+        self.0.order_of(left, right).reverse()
+    }
+}
+
+/// An orderer which orders items by a key extracted from each item, allowing for the
+/// set to behave like a map with keys stored inline within its values.
+///
+/// **Note:** the key is extracted on every comparison, so cheap key functions (such as
+/// reading a field) are preferred.
+///
+/// ### Example
+///
+/// ```
+/// use ord_by_set::{OrdBySet, OrderByKey};
+///
+/// struct Person {
+///     id: u32,
+///     name: &'static str,
+/// }
+///
+/// let set = OrdBySet::new_with_order(OrderByKey(|person: &Person| person.id)).with_items([
+///     Person { id: 2, name: "Bob" },
+///     Person { id: 1, name: "Alice" },
+/// ]);
+///
+/// let key = Person { id: 2, name: "" };
+/// assert_eq!(set.get_first(&key).unwrap().name, "Bob");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct OrderByKey<KeyFn>(pub KeyFn);
+
+impl<T, Key, KeyFn> Order<T> for OrderByKey<KeyFn>
+where
+    Key: Ord,
+    KeyFn: Fn(&T) -> Key,
+{
+    fn order_of(&self, left: &T, right: &T) -> Ordering {
+        (self.0)(left).cmp(&(self.0)(right))
     }
 }
 
 /// An ordering implementation that just defers to [`Ord`]
-#[derive(Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
 pub struct FullOrd;
 
+impl FullOrd {
+    /// Reverses the ordering, such that the greatest items are ordered first. This is the
+    /// same as [`Order::reverse`], but avoids needing to specify which type is being
+    /// ordered, as `FullOrd` is an orderer for every type that implements [`Ord`].
+    pub fn reverse(self) -> ReverseOrder<Self> {
+        ReverseOrder(self)
+    }
+}
+
 impl<T: Ord> Order<T> for FullOrd {
     fn order_of(&self, left: &T, right: &T) -> Ordering {
         left.cmp(right)
@@ -0,0 +1,20 @@
+use crate::{OrdBySet, Order};
+use alloc::{boxed::Box, vec::Vec};
+use quickcheck::{Arbitrary, Gen};
+
+/// Generates an arbitrary list of items, which are then sorted using a default-initialized
+/// orderer. Shrinking shrinks the list of items, re-sorting each candidate, so every
+/// generated set is properly sorted.
+impl<T, Orderer> Arbitrary for OrdBySet<T, Orderer>
+where
+    T: Arbitrary,
+    Orderer: Order<T> + Default + Clone + 'static,
+{
+    fn arbitrary(g: &mut Gen) -> Self {
+        Vec::arbitrary(g).into()
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.storage.shrink().map(Self::from))
+    }
+}
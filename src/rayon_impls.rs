@@ -0,0 +1,44 @@
+use crate::{OrdBySet, Order};
+use alloc::vec::Vec;
+use rayon::iter::{
+    FromParallelIterator, IntoParallelIterator, IntoParallelRefIterator,
+    IntoParallelRefMutIterator, ParallelExtend,
+};
+
+impl<T: Send, Orderer: Order<T>> IntoParallelIterator for OrdBySet<T, Orderer> {
+    type Iter = rayon::vec::IntoIter<T>;
+    type Item = T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.storage.into_par_iter()
+    }
+}
+
+impl<'a, T: Sync, Orderer: Order<T>> IntoParallelIterator for &'a OrdBySet<T, Orderer> {
+    type Iter = rayon::slice::Iter<'a, T>;
+    type Item = &'a T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.storage.par_iter()
+    }
+}
+
+/// Iterates mutably over each item in parallel. Like [`OrdBySet::iter_mut`], the state of
+/// the set is unspecified if the ordering of any item is changed.
+impl<'a, T: Send, Orderer: Order<T>> IntoParallelIterator for &'a mut OrdBySet<T, Orderer> {
+    type Iter = rayon::slice::IterMut<'a, T>;
+    type Item = &'a mut T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.storage.par_iter_mut()
+    }
+}
+
+impl<T: Send, Orderer: Order<T> + Default> FromParallelIterator<T> for OrdBySet<T, Orderer> {
+    fn from_par_iter<I: IntoParallelIterator<Item = T>>(par_iter: I) -> Self {
+        let mut storage = Vec::new();
+        storage.par_extend(par_iter);
+
+        Self::new().with_items(storage)
+    }
+}
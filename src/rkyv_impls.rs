@@ -0,0 +1,70 @@
+use crate::{OrdBySet, Order};
+use alloc::vec::Vec;
+use rkyv::{
+    ser::{ScratchSpace, Serializer},
+    vec::{ArchivedVec, VecResolver},
+    Archive, Deserialize, DeserializeUnsized, Fallible, Serialize,
+};
+
+/// Archives the items of the set, in sorted order, as an [`ArchivedVec`]. The orderer
+/// itself is not archived.
+impl<T: Archive, Orderer: Order<T>> Archive for OrdBySet<T, Orderer> {
+    type Archived = ArchivedVec<T::Archived>;
+    type Resolver = VecResolver;
+
+    #[inline]
+    unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived) {
+        ArchivedVec::resolve_from_slice(&self.storage, pos, resolver, out);
+    }
+}
+
+impl<T, Orderer, S> Serialize<S> for OrdBySet<T, Orderer>
+where
+    T: Serialize<S>,
+    Orderer: Order<T>,
+    S: ScratchSpace + Serializer + ?Sized,
+{
+    #[inline]
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedVec::serialize_from_slice(&self.storage, serializer)
+    }
+}
+
+/// Deserializes the archived items, using a default-initialized orderer to sort them.
+/// The items are always re-sorted, as the order they were archived in may not match the
+/// orderer being deserialized into. See [`OrdBySet::from_archived_sorted`] for
+/// deserializing without re-sorting.
+impl<T, Orderer, D> Deserialize<OrdBySet<T, Orderer>, D> for ArchivedVec<T::Archived>
+where
+    T: Archive,
+    Orderer: Order<T> + Default,
+    D: Fallible + ?Sized,
+    [T::Archived]: DeserializeUnsized<[T], D>,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<OrdBySet<T, Orderer>, D::Error> {
+        Deserialize::<Vec<T>, D>::deserialize(self, deserializer).map(OrdBySet::from)
+    }
+}
+
+impl<T: Archive, Orderer: Order<T>> OrdBySet<T, Orderer> {
+    /// Deserializes an archived set without re-sorting its items, trusting that they were
+    /// archived in an order matching `orderer`.
+    ///
+    /// **Note:** if the items were archived using a different ordering scheme than
+    /// `orderer` then the behavior of the resulting set is unspecified (albeit safe/sound
+    /// in the context of Rust's safety guarantees), the same as with
+    /// [`from_sorted_unchecked`](OrdBySet::from_sorted_unchecked).
+    pub fn from_archived_sorted<D>(
+        orderer: Orderer,
+        archived: &ArchivedVec<T::Archived>,
+        deserializer: &mut D,
+    ) -> Result<Self, D::Error>
+    where
+        D: Fallible + ?Sized,
+        [T::Archived]: DeserializeUnsized<[T], D>,
+    {
+        let storage = Deserialize::<Vec<T>, D>::deserialize(archived, deserializer)?;
+
+        Ok(Self::from_sorted_unchecked(orderer, storage))
+    }
+}
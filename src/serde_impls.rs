@@ -0,0 +1,24 @@
+use crate::{OrdBySet, Order};
+use alloc::vec::Vec;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serializes the items of the set as a sequence, in sorted order. The orderer itself is
+/// not serialized.
+impl<T: Serialize, Orderer: Order<T>> Serialize for OrdBySet<T, Orderer> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.storage.serialize(serializer)
+    }
+}
+
+/// Deserializes a sequence of items, using a default-initialized orderer to sort them.
+/// The items are always re-sorted, as the order they were serialized in may not match
+/// the orderer being deserialized into.
+impl<'de, T, Orderer> Deserialize<'de> for OrdBySet<T, Orderer>
+where
+    T: Deserialize<'de>,
+    Orderer: Order<T> + Default,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::deserialize(deserializer).map(Self::from)
+    }
+}
@@ -0,0 +1,158 @@
+use crate::{OrdBySet, Order};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+impl<T, Orderer: Order<T>> OrdBySet<T, Orderer> {
+    /// Walks the groups of both sets in order, calling `f` with each pair of equivelant
+    /// groups. Groups which are only present in one of the sets are paired with an empty
+    /// slice.
+    fn for_each_group_pair<'a, F>(&'a self, other: &'a Self, mut f: F)
+    where
+        F: FnMut(&'a [T], &'a [T]),
+    {
+        let (mut left, mut right) = (0, 0);
+
+        while left < self.storage.len() || right < other.storage.len() {
+            let order = match (self.storage.get(left), other.storage.get(right)) {
+                (Some(left), Some(right)) => self.orderer.order_of(left, right),
+                (Some(_), None) => Ordering::Less,
+                (None, _) => Ordering::Greater,
+            };
+
+            let left_end = if order.is_le() {
+                self.group_end(left)
+            } else {
+                left
+            };
+            let right_end = if order.is_ge() {
+                other.group_end(right)
+            } else {
+                right
+            };
+
+            f(
+                &self.storage[left..left_end],
+                &other.storage[right..right_end],
+            );
+
+            left = left_end;
+            right = right_end;
+        }
+    }
+}
+
+impl<T: Clone, Orderer: Order<T> + Clone> OrdBySet<T, Orderer> {
+    /// Creates a new set containing the items of both sets, such that the number of items
+    /// in each group of equivelant items is the sum of the two sets' counts. The orderer
+    /// of the new set is cloned from `self`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let a = OrdBySet::fully_ordered().with_items([1, 1, 2]);
+    /// let b = OrdBySet::fully_ordered().with_items([1, 3]);
+    /// let union = a.union(&b);
+    ///
+    /// assert_eq!(union.count(&1), 3);
+    /// assert_eq!(union.count(&2), 1);
+    /// assert_eq!(union.count(&3), 1);
+    /// ```
+    pub fn union(&self, other: &Self) -> Self {
+        let mut storage = Vec::with_capacity(self.storage.len() + other.storage.len());
+
+        self.for_each_group_pair(other, |left, right| {
+            storage.extend_from_slice(left);
+            storage.extend_from_slice(right);
+        });
+
+        Self::from_sorted_unchecked(self.orderer.clone(), storage)
+    }
+
+    /// Creates a new set containing the groups of equivelant items present in both sets,
+    /// such that the number of items in each group is the smaller of the two sets' counts.
+    /// The items and the orderer are cloned from `self`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let a = OrdBySet::fully_ordered().with_items([1, 1, 2, 3]);
+    /// let b = OrdBySet::fully_ordered().with_items([1, 2, 2]);
+    /// let intersection = a.intersection(&b);
+    ///
+    /// assert_eq!(intersection.count(&1), 1);
+    /// assert_eq!(intersection.count(&2), 1);
+    /// assert!(!intersection.contains(&3));
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut storage = Vec::new();
+
+        self.for_each_group_pair(other, |left, right| {
+            storage.extend_from_slice(&left[..left.len().min(right.len())]);
+        });
+
+        Self::from_sorted_unchecked(self.orderer.clone(), storage)
+    }
+
+    /// Creates a new set containing the items of `self` which are not matched by an
+    /// equivelant item in `other`, such that the number of items in each group is the
+    /// count in `self` minus the count in `other` (if any remain). The items and the
+    /// orderer are cloned from `self`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let a = OrdBySet::fully_ordered().with_items([1, 1, 1, 2]);
+    /// let b = OrdBySet::fully_ordered().with_items([1, 2]);
+    /// let difference = a.difference(&b);
+    ///
+    /// assert_eq!(difference.count(&1), 2);
+    /// assert!(!difference.contains(&2));
+    /// ```
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut storage = Vec::new();
+
+        self.for_each_group_pair(other, |left, right| {
+            storage.extend_from_slice(&left[..left.len().saturating_sub(right.len())]);
+        });
+
+        Self::from_sorted_unchecked(self.orderer.clone(), storage)
+    }
+
+    /// Creates a new set containing the items of either set which are not matched by an
+    /// equivelant item in the other, such that the number of items in each group is the
+    /// difference between the two sets' counts. The items are cloned from whichever set
+    /// has more of them, while the orderer is cloned from `self`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use ord_by_set::OrdBySet;
+    ///
+    /// let a = OrdBySet::fully_ordered().with_items([1, 1, 2]);
+    /// let b = OrdBySet::fully_ordered().with_items([1, 1, 1, 3]);
+    /// let symmetric_difference = a.symmetric_difference(&b);
+    ///
+    /// assert_eq!(symmetric_difference.count(&1), 1);
+    /// assert_eq!(symmetric_difference.count(&2), 1);
+    /// assert_eq!(symmetric_difference.count(&3), 1);
+    /// ```
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        let mut storage = Vec::new();
+
+        self.for_each_group_pair(other, |left, right| {
+            if left.len() > right.len() {
+                storage.extend_from_slice(&left[..left.len() - right.len()]);
+            } else {
+                storage.extend_from_slice(&right[..right.len() - left.len()]);
+            }
+        });
+
+        Self::from_sorted_unchecked(self.orderer.clone(), storage)
+    }
+}
@@ -0,0 +1,22 @@
+/// A policy for when an [`OrdBySet`](crate::OrdBySet) should automatically shrink its
+/// allocated capacity after items are removed. Set via
+/// [`OrdBySet::set_shrink_policy`](crate::OrdBySet::set_shrink_policy).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ShrinkPolicy {
+    /// Never automatically shrink. This is the default, and matches the behavior of `Vec`.
+    #[default]
+    Never,
+    /// Shrink the capacity to fit the items of the set once fewer than a quarter of the
+    /// capacity is in use.
+    WhenQuarterFull,
+}
+
+impl ShrinkPolicy {
+    /// Check whether a set with the given length and capacity should be shrunk
+    pub(crate) fn should_shrink(self, len: usize, capacity: usize) -> bool {
+        match self {
+            ShrinkPolicy::Never => false,
+            ShrinkPolicy::WhenQuarterFull => len.saturating_mul(4) < capacity,
+        }
+    }
+}
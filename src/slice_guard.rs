@@ -2,12 +2,20 @@ use crate::{OrdBySet, Order};
 use core::ops::Range;
 
 /// A drop guard that ensures the [`OrdBySet`] is properly sorted after any modifications
-/// to the underlying slice are made
+/// to the underlying slice are made. If the slice is never mutably accessed, the set is
+/// not re-sorted.
 pub struct SliceGuard<'set, T, Orderer: Order<T>>(
     pub(crate) &'set mut OrdBySet<T, Orderer>,
     pub(crate) Range<usize>,
+    bool,
 );
 
+impl<'set, T, Orderer: Order<T>> SliceGuard<'set, T, Orderer> {
+    pub(crate) fn new(set: &'set mut OrdBySet<T, Orderer>, range: Range<usize>) -> Self {
+        SliceGuard(set, range, false)
+    }
+}
+
 impl<'set, T, Orderer: Order<T>> core::ops::Deref for SliceGuard<'set, T, Orderer> {
     type Target = [T];
 
@@ -18,12 +26,19 @@ impl<'set, T, Orderer: Order<T>> core::ops::Deref for SliceGuard<'set, T, Ordere
 
 impl<'set, T, Orderer: Order<T>> core::ops::DerefMut for SliceGuard<'set, T, Orderer> {
     fn deref_mut(&mut self) -> &mut Self::Target {
+        self.2 = true;
         &mut self.0.storage[self.1.clone()]
     }
 }
 
 impl<'set, T, Orderer: Order<T>> Drop for SliceGuard<'set, T, Orderer> {
     fn drop(&mut self) {
-        self.0.orderer.sort_slice(&mut self.0.storage);
+        // nothing can have been modified without going through `deref_mut`
+        if !self.2 {
+            return;
+        }
+
+        // only the guarded range can have been modified
+        self.0.resort_range(self.1.clone());
     }
 }
@@ -0,0 +1,33 @@
+use crate::{OrdBySet, Order};
+use proptest::collection::{vec, SizeRange};
+use proptest::strategy::Strategy;
+
+/// Creates a [`Strategy`] for generating sets with a number of items within `size`, each
+/// generated by `element`. The orderer is default-initialized, and the items are sorted
+/// by it every time a set is generated, including while shrinking.
+///
+/// ### Example
+///
+/// ```
+/// use ord_by_set::{ord_by_set_strategy, FullOrd};
+/// use proptest::prelude::*;
+///
+/// proptest! {
+///     fn first_is_smallest(set in ord_by_set_strategy::<FullOrd, _, _>(any::<u32>(), 1..50)) {
+///         prop_assert!(set.iter().all(|item| set.first().unwrap() <= item));
+///     }
+/// }
+///
+/// first_is_smallest();
+/// ```
+pub fn ord_by_set_strategy<Orderer, S, Size>(
+    element: S,
+    size: Size,
+) -> impl Strategy<Value = OrdBySet<S::Value, Orderer>>
+where
+    Orderer: Order<S::Value> + Default,
+    S: Strategy,
+    Size: Into<SizeRange>,
+{
+    vec(element, size).prop_map(OrdBySet::<S::Value, Orderer>::from)
+}
@@ -1,4 +1,5 @@
 use crate::*;
+use alloc::vec;
 
 fn ord_set<T: Ord, const N: usize>(from: [T; N]) -> OrdBySet<T> {
     let mut set = OrdBySet::new();
@@ -39,9 +40,1346 @@ fn slice_range_unsorted() {
             .with_items([2, 1, 3, 1, 3, 4])
             .range(&2, &4)
             .unwrap()
-            .iter()
-            .copied()
-            .collect::<Vec<_>>(),
+            .to_vec(),
         [2, 3, 3, 4]
     );
 }
+
+#[test]
+fn extend_interleaved() {
+    let mut set = ord_set([5, 1, 3]);
+    set.extend([4, 1, 2, 5]);
+
+    assert_eq!(set.len(), 7);
+    assert_eq!(set.count(&1), 2);
+    assert_eq!(set.count(&5), 2);
+    assert_eq!(set.get(&4), Some(&[4][..]));
+    assert_eq!(
+        set.iter().copied().collect::<Vec<_>>(),
+        [1, 1, 2, 3, 4, 5, 5]
+    );
+
+    set.extend(core::iter::empty::<i32>());
+    assert_eq!(set.len(), 7);
+}
+
+#[test]
+fn shrink_after_clear() {
+    let mut set = OrdBySet::fully_ordered().with_items((0..1000).collect::<Vec<_>>());
+    set.clear();
+    assert!(set.capacity() >= 1000);
+
+    set.shrink_to_fit();
+    assert_eq!(set.capacity(), 0);
+
+    set.reserve(100);
+    assert!(set.capacity() >= 100);
+    set.shrink_to(10);
+    assert!(set.capacity() >= 10 && set.capacity() < 100);
+}
+
+#[test]
+fn slice_guard_matches_full_sort() {
+    // simple LCG so the test is deterministic without pulling in a rng
+    let mut seed = 0x2545_f491_u32;
+    let mut next = move || {
+        seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+        (seed >> 16) % 1000
+    };
+
+    let mut set =
+        OrdBySet::fully_ordered().with_items((0..2000).map(|_| next()).collect::<Vec<_>>());
+    let mut expected = set.iter().copied().collect::<Vec<_>>();
+
+    for round in 0..200 {
+        let low = next();
+        let high = low + next() % 50;
+
+        if let Some(mut slice) = set.range_mut(&low, &high) {
+            for item in slice.iter_mut() {
+                // alternate between edits that stay within the range and ones that escape it
+                *item = if round % 2 == 0 {
+                    low + (*item * 7) % (high - low + 1)
+                } else {
+                    next()
+                };
+            }
+
+            expected.retain(|item| !(low..=high).contains(item));
+            expected.extend_from_slice(&slice);
+        }
+
+        expected.sort();
+        assert_eq!(set.iter().copied().collect::<Vec<_>>(), expected);
+    }
+}
+
+/// Orders pairs by their first element only, so items with differing second elements
+/// are equivelant but not equal
+#[derive(Clone, Default)]
+struct ByKey;
+
+impl Order<(u32, char)> for ByKey {
+    fn order_of(&self, left: &(u32, char), right: &(u32, char)) -> core::cmp::Ordering {
+        left.0.cmp(&right.0)
+    }
+}
+
+fn loose_set<const N: usize>(from: [(u32, char); N]) -> OrdBySet<(u32, char), ByKey> {
+    OrdBySet::new().with_items(from)
+}
+
+#[test]
+fn loose_equality() {
+    let set = loose_set([(1, 'a'), (1, 'b'), (2, 'c')]);
+
+    assert_eq!(set, loose_set([(1, 'x'), (1, 'y'), (2, 'z')]));
+    assert_ne!(set, loose_set([(1, 'a'), (2, 'b'), (2, 'c')]));
+    assert_ne!(set, loose_set([(1, 'a'), (1, 'b')]));
+
+    assert!(set.eq_exact(&loose_set([(2, 'c'), (1, 'b'), (1, 'a')])));
+    assert!(!set.eq_exact(&loose_set([(1, 'a'), (1, 'a'), (2, 'c')])));
+    assert!(!set.eq_exact(&loose_set([(1, 'x'), (1, 'y'), (2, 'z')])));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trip() {
+    #[derive(Default)]
+    struct Descending;
+
+    impl Order<u32> for Descending {
+        fn order_of(&self, left: &u32, right: &u32) -> core::cmp::Ordering {
+            right.cmp(left)
+        }
+    }
+
+    let set: OrdBySet<u32, Descending> = OrdBySet::new().with_items([2, 3, 1, 3]);
+    let json = serde_json::to_string(&set).unwrap();
+    assert_eq!(json, "[3,3,2,1]");
+
+    let set: OrdBySet<u32, Descending> = serde_json::from_str("[1,3,2,3]").unwrap();
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), [3, 3, 2, 1]);
+    assert_eq!(set.count(&3), 2);
+}
+
+#[test]
+fn union_counts() {
+    let union = ord_set([1, 1, 2]).union(&ord_set([1, 3]));
+
+    assert!(union.is_sorted());
+    assert_eq!(union.count(&1), 3);
+    assert_eq!(union.count(&2), 1);
+    assert_eq!(union.count(&3), 1);
+    assert_eq!(union.len(), 5);
+
+    assert_eq!(ord_set([]).union(&ord_set([2, 1])).len(), 2);
+}
+
+#[test]
+fn intersection_counts() {
+    let intersection = ord_set([1, 1, 2, 3]).intersection(&ord_set([1, 2, 2]));
+
+    assert_eq!(intersection.iter().copied().collect::<Vec<_>>(), [1, 2]);
+    assert!(ord_set([1, 2]).intersection(&ord_set([3, 4])).is_empty());
+    assert!(ord_set([1, 2]).intersection(&ord_set([])).is_empty());
+}
+
+#[test]
+fn difference_counts() {
+    let difference = ord_set([1, 1, 1, 2]).difference(&ord_set([1, 2]));
+    assert_eq!(difference.iter().copied().collect::<Vec<_>>(), [1, 1]);
+
+    let difference = ord_set([1, 2]).difference(&ord_set([1, 1, 3]));
+    assert_eq!(difference.iter().copied().collect::<Vec<_>>(), [2]);
+
+    let symmetric_difference = ord_set([1, 1]).symmetric_difference(&ord_set([1, 1, 1]));
+    assert_eq!(
+        symmetric_difference.iter().copied().collect::<Vec<_>>(),
+        [1]
+    );
+
+    let symmetric_difference = ord_set([1, 2, 2]).symmetric_difference(&ord_set([2, 3]));
+    assert_eq!(
+        symmetric_difference.iter().copied().collect::<Vec<_>>(),
+        [1, 2, 3]
+    );
+}
+
+#[test]
+fn groups_split_on_equivelance() {
+    assert_eq!(OrdBySet::<u32>::new().groups().count(), 0);
+
+    let set = loose_set([(2, 'a'), (1, 'b'), (2, 'c'), (3, 'd'), (1, 'e')]);
+    let groups = set
+        .groups()
+        .map(|group| group.iter().map(|(_, tag)| *tag).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    assert_eq!(groups, [vec!['b', 'e'], vec!['a', 'c'], vec!['d']]);
+}
+
+#[test]
+fn dedup_keeps_one_per_group() {
+    let mut set = loose_set([(1, 'a'), (1, 'b'), (2, 'c'), (3, 'd'), (3, 'e')]);
+    let mut last = set.clone();
+
+    set.dedup();
+    assert_eq!(
+        set.iter().copied().collect::<Vec<_>>(),
+        [(1, 'a'), (2, 'c'), (3, 'd')]
+    );
+
+    last.dedup_keep_last();
+    assert_eq!(
+        last.iter().copied().collect::<Vec<_>>(),
+        [(1, 'b'), (2, 'c'), (3, 'e')]
+    );
+}
+
+#[test]
+fn cursor_movement() {
+    let set = ord_set([1, 2, 2, 3]);
+    let mut cursor = set.cursor();
+
+    let mut forward = Vec::new();
+    while let Some(item) = cursor.current() {
+        forward.push(*item);
+        cursor.move_next();
+    }
+    assert_eq!(forward, [1, 2, 2, 3]);
+
+    // at the ghost position past the end
+    assert_eq!(cursor.index(), None);
+    assert_eq!(cursor.peek_next(), Some(&1));
+    assert_eq!(cursor.peek_prev(), Some(&3));
+
+    cursor.move_prev();
+    assert_eq!(cursor.index(), Some(3));
+    assert_eq!(cursor.peek_next(), None);
+    cursor.move_prev();
+    cursor.move_prev();
+    assert_eq!(cursor.index(), Some(1));
+    assert_eq!(cursor.current(), Some(&2));
+
+    let empty = OrdBySet::<u32>::new();
+    let mut cursor = empty.cursor();
+    assert_eq!(cursor.current(), None);
+    cursor.move_next();
+    assert_eq!(cursor.current(), None);
+    assert_eq!(cursor.peek_prev(), None);
+}
+
+#[test]
+fn cursor_mut_resorts() {
+    let mut set = ord_set([1, 2, 3, 4]);
+
+    let mut cursor = set.cursor_mut();
+    cursor.move_next();
+    *cursor.current_mut().unwrap() = 10;
+    cursor.move_next();
+    assert_eq!(cursor.current(), Some(&3));
+    assert_eq!(cursor.peek_prev(), Some(&10));
+    drop(cursor);
+
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), [1, 3, 4, 10]);
+}
+
+#[test]
+fn cursor_mut_only_sorts_touched_range() {
+    use core::cell::Cell;
+
+    let calls = Cell::new(0);
+    let counting = |left: &u32, right: &u32| {
+        calls.set(calls.get() + 1);
+        left.cmp(right)
+    };
+    let items = (0..100).collect::<Vec<_>>();
+    let mut set = OrdBySet::new_with_order(counting).with_items(items);
+
+    // debug builds also compare every adjacent pair once the cursor is dropped
+    let pairs = set.len() - 1;
+    let debug_check = if cfg!(debug_assertions) { pairs } else { 0 };
+
+    let mut cursor = set.cursor_mut();
+    cursor.move_next();
+    let before = calls.get();
+    drop(cursor);
+    assert_eq!(calls.get(), before);
+
+    // swapping two neighboring items only needs them and their neighbors compared
+    let mut cursor = set.cursor_mut();
+    cursor.move_next();
+    *cursor.current_mut().unwrap() = 2;
+    cursor.move_next();
+    *cursor.current_mut().unwrap() = 1;
+    let before = calls.get();
+    drop(cursor);
+
+    assert!(calls.get() - before <= 3 + debug_check);
+    assert_eq!(
+        set.iter().copied().collect::<Vec<_>>(),
+        (0..100).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn get_first_is_group_start() {
+    let mut set = loose_set([(2, 'a'), (1, 'b'), (1, 'c'), (1, 'd'), (0, 'e')]);
+    let group = set.get(&(1, ' ')).unwrap();
+
+    assert!(core::ptr::eq(set.get_first(&(1, ' ')).unwrap(), &group[0]));
+    assert_eq!(set.get_first(&(1, ' ')), Some(&(1, 'b')));
+    assert_eq!(*set.get_first_mut(&(1, ' ')).unwrap(), (1, 'b'));
+    assert_eq!(set.get_first(&(3, ' ')), None);
+}
+
+#[test]
+fn remove_first_matches_get_first() {
+    let mut set = loose_set([(1, 'a'), (3, 'b'), (1, 'c'), (1, 'd')]);
+
+    for _ in 0..3 {
+        let first = *set.get_first(&(1, ' ')).unwrap();
+        assert_eq!(set.get(&(1, ' ')).unwrap()[0], first);
+        assert_eq!(set.remove_first(&(1, ' ')), Some(first));
+    }
+
+    assert_eq!(set.remove_first(&(1, ' ')), None);
+    assert_eq!(set.len(), 1);
+}
+
+#[test]
+fn retain_mut_resorts() {
+    let mut set = ord_set([5, 1, 4, 2, 3]);
+    set.retain_mut(|x| {
+        *x = (*x * 3) % 7;
+        *x != 1
+    });
+
+    assert!(set.is_sorted());
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), [2, 3, 5, 6]);
+    assert_eq!(set.get(&6), Some(&[6][..]));
+    assert!(!set.contains(&1));
+}
+
+#[test]
+fn extract_if_edge_cases() {
+    let mut set = ord_set([3, 1, 2]);
+
+    assert!(set.extract_if(|_| false).is_empty());
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+
+    assert_eq!(set.extract_if(|_| true), [1, 2, 3]);
+    assert!(set.is_empty());
+}
+
+#[test]
+fn split_off_boundaries() {
+    let mut set = ord_set([1, 2, 3, 3, 4]);
+    let split = set.split_off(&3);
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), [1, 2]);
+    assert_eq!(split.iter().copied().collect::<Vec<_>>(), [3, 3, 4]);
+
+    let mut set = ord_set([1, 2, 4]);
+    assert_eq!(set.split_off(&3).iter().copied().collect::<Vec<_>>(), [4]);
+    assert!(set.split_off(&5).is_empty());
+    assert_eq!(set.split_off(&0).len(), 2);
+    assert!(set.is_empty());
+}
+
+#[test]
+fn append_merges() {
+    let mut set = ord_set([2, 4]);
+    let mut other = ord_set([1, 3, 5]);
+    set.append(&mut other);
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+    assert!(other.is_empty());
+
+    let mut set = loose_set([(1, 'a'), (2, 'b')]);
+    set.append(&mut loose_set([(1, 'c'), (0, 'd'), (2, 'e')]));
+    assert_eq!(
+        set.iter().copied().collect::<Vec<_>>(),
+        [(0, 'd'), (1, 'a'), (1, 'c'), (2, 'b'), (2, 'e')]
+    );
+
+    set.append(&mut OrdBySet::new());
+    assert_eq!(set.len(), 5);
+}
+
+#[test]
+fn range_bounds_exclusive() {
+    use core::ops::Bound::{Excluded, Included, Unbounded};
+
+    let set = ord_set([1, 2, 2, 3, 4]);
+
+    assert_eq!(set.range_bounds((Excluded(1), Excluded(4))), [2, 2, 3]);
+    assert_eq!(set.range_bounds((Excluded(2), Included(4))), [3, 4]);
+    assert_eq!(set.range_bounds((Unbounded, Excluded(2))), [1]);
+    assert!(set.range_bounds((Excluded(2), Excluded(3))).is_empty());
+    assert!(set.range_bounds(5..).is_empty());
+    assert!(OrdBySet::<u32>::new().range_bounds(..).is_empty());
+}
+
+#[test]
+fn range_equal_and_reversed_bounds() {
+    let set = ord_set([1, 2, 2, 3]);
+
+    assert_eq!(set.range(&2, &2), Some(&[2, 2][..]));
+    assert_eq!(set.range(&4, &4), None);
+    assert_eq!(set.range(&3, &1), None);
+    assert_eq!(set.range(&1, &3).map(<[_]>::len), Some(4));
+}
+
+#[test]
+fn borrowed_into_iter() {
+    let mut set = ord_set([3, 1, 2]);
+
+    let mut sum = 0;
+    for item in &set {
+        sum += *item;
+    }
+    assert_eq!(sum, 6);
+
+    // doubling preserves the ordering of items
+    for item in &mut set {
+        *item *= 2;
+    }
+    assert_eq!(set.as_slice(), [2, 4, 6]);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn rayon_parallel_sum() {
+    use rayon::prelude::*;
+
+    let items = (0..100_000u64).rev().collect::<alloc::vec::Vec<_>>();
+    let set: OrdBySet<u64> = items.into_par_iter().collect();
+    assert!(set.is_sorted());
+    assert_eq!(set.len(), 100_000);
+
+    let sequential: u64 = set.iter().sum();
+    assert_eq!(set.par_iter().sum::<u64>(), sequential);
+    assert_eq!(set.into_par_iter().sum::<u64>(), sequential);
+}
+
+#[test]
+fn chained_order_breaks_ties() {
+    let set = OrdBySet::new_with_order(ByKey.then(FullOrd)).with_items([
+        (2, 'b'),
+        (1, 'z'),
+        (2, 'a'),
+        (1, 'y'),
+    ]);
+
+    assert_eq!(set.as_slice(), [(1, 'y'), (1, 'z'), (2, 'a'), (2, 'b')]);
+    assert_eq!(set.count(&(2, 'a')), 1);
+}
+
+#[test]
+fn reverse_order_max_first() {
+    let mut set = OrdBySet::new_with_order(FullOrd.reverse());
+    for item in [3, 1, 2] {
+        set.insert(item);
+    }
+
+    assert_eq!(set.first(), Some(&3));
+    assert_eq!(set.as_slice(), [3, 2, 1]);
+
+    let set = OrdBySet::new_with_order(ByKey.reverse()).with_items([(1, 'a'), (2, 'b')]);
+    assert_eq!(set.first(), Some(&(2, 'b')));
+}
+
+#[test]
+fn filter_map_into_rekeys() {
+    let set = ord_set([1, 2, 3, 4, 5, 6]);
+
+    // drop odd items and reverse the ordering of the rest
+    let mapped = set.filter_map_into(FullOrd, |x| (x % 2 == 0).then_some(10 - x));
+
+    assert!(mapped.is_sorted());
+    assert_eq!(mapped.as_slice(), [4, 6, 8]);
+}
+
+#[test]
+fn get_or_insert_with_calls_lazily() {
+    let mut set = loose_set([(1, 'a'), (1, 'b'), (3, 'c')]);
+
+    let existing = *set.get_or_insert_with(&(1, 'z'), || panic!("group already exists"));
+    assert_eq!(existing, (1, 'a'));
+
+    assert_eq!(*set.get_or_insert_with(&(2, 'z'), || (2, 'd')), (2, 'd'));
+    assert_eq!(set.as_slice(), [(1, 'a'), (1, 'b'), (2, 'd'), (3, 'c')]);
+}
+
+#[test]
+fn entry_occupied_and_vacant() {
+    let mut set = loose_set([(1, 'a'), (2, 'b'), (2, 'c'), (4, 'd')]);
+
+    match set.entry((2, 'z')) {
+        Entry::Occupied(entry) => {
+            assert_eq!(entry.count(), 2);
+            assert_eq!(entry.range(), 1..3);
+            assert_eq!(entry.get(), [(2, 'b'), (2, 'c')]);
+
+            // move one of the items out of the group
+            entry.into_mut()[0].0 = 5;
+        }
+        Entry::Vacant(_) => panic!("group should be occupied"),
+    }
+    assert!(set.is_sorted());
+    assert_eq!(set.count(&(2, ' ')), 1);
+
+    match set.entry((3, 'z')) {
+        Entry::Occupied(_) => panic!("group should be vacant"),
+        Entry::Vacant(entry) => assert_eq!(*entry.insert((3, 'e')), (3, 'e')),
+    }
+    match set.entry((3, 'z')) {
+        Entry::Occupied(entry) => assert_eq!(entry.remove_all(), [(3, 'e')]),
+        Entry::Vacant(_) => panic!("group should be occupied"),
+    }
+    match set.entry((6, 'z')) {
+        Entry::Occupied(_) => panic!("group should be vacant"),
+        // an item which isn't equivelant to the key is still inserted in order
+        Entry::Vacant(entry) => assert_eq!(*entry.insert((0, 'f')), (0, 'f')),
+    }
+
+    assert!(set.is_sorted());
+    assert_eq!(
+        set.as_slice(),
+        [(0, 'f'), (1, 'a'), (2, 'c'), (4, 'd'), (5, 'b')]
+    );
+}
+
+#[test]
+fn replace_first_or_insert() {
+    let mut set = loose_set([(1, 'a'), (1, 'b'), (3, 'c')]);
+
+    assert_eq!(set.replace_first((1, 'x')), Some((1, 'a')));
+    assert_eq!(set.as_slice(), [(1, 'x'), (1, 'b'), (3, 'c')]);
+
+    assert_eq!(set.replace_first((2, 'y')), None);
+    assert_eq!(set.replace_first((4, 'z')), None);
+    assert_eq!(
+        set.as_slice(),
+        [(1, 'x'), (1, 'b'), (2, 'y'), (3, 'c'), (4, 'z')]
+    );
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn arbitrary_sets_are_sorted() {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    let bytes = (0..4096u32)
+        .map(|i| (i.wrapping_mul(2_654_435_761) >> 13) as u8)
+        .collect::<Vec<_>>();
+    let mut u = Unstructured::new(&bytes);
+
+    for _ in 0..16 {
+        let set = OrdBySet::<i16>::arbitrary(&mut u).unwrap();
+        assert!(set.is_sorted());
+
+        let set = OrdBySet::<(u32, char), ByKey>::arbitrary(&mut u).unwrap();
+        assert!(set.is_sorted());
+    }
+}
+
+#[cfg(feature = "proptest")]
+proptest::proptest! {
+    #[test]
+    fn strategy_sets_are_sorted(
+        set in ord_by_set_strategy::<ByKey, _, _>(
+            proptest::arbitrary::any::<(u32, char)>(),
+            0..64,
+        )
+    ) {
+        proptest::prop_assert!(set.is_sorted());
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+#[test]
+fn quickcheck_shrinks_are_sorted() {
+    use quickcheck::{Arbitrary, Gen};
+
+    let mut g = Gen::new(32);
+
+    for _ in 0..16 {
+        let set = OrdBySet::<(u32, char), ByKey>::arbitrary(&mut g);
+        assert!(set.is_sorted());
+        assert!(set.shrink().all(|shrunk| shrunk.is_sorted()));
+    }
+}
+
+#[cfg(feature = "rkyv")]
+#[test]
+fn rkyv_round_trip() {
+    use rkyv::{Deserialize, Infallible};
+
+    let set = ord_set([3, 1, 2, 2]);
+    let bytes = rkyv::to_bytes::<_, 256>(&set).unwrap();
+    let archived = unsafe { rkyv::archived_root::<OrdBySet<i32>>(&bytes) };
+    assert_eq!(archived.as_slice(), [1, 2, 2, 3]);
+
+    let resorted: OrdBySet<i32> = archived.deserialize(&mut Infallible).unwrap();
+    assert_eq!(resorted.as_slice(), [1, 2, 2, 3]);
+
+    let trusted = OrdBySet::from_archived_sorted(FullOrd, archived, &mut Infallible).unwrap();
+    assert!(trusted.is_sorted());
+    assert_eq!(trusted.count(&2), 2);
+}
+
+#[cfg(feature = "borsh")]
+#[test]
+fn borsh_round_trip() {
+    use core::cmp::Ordering;
+
+    #[derive(Default)]
+    struct ByLastDigit;
+
+    impl Order<u32> for ByLastDigit {
+        fn order_of(&self, left: &u32, right: &u32) -> Ordering {
+            (left % 10).cmp(&(right % 10))
+        }
+    }
+
+    let set = OrdBySet::new_with_order(ByLastDigit).with_items([13, 21, 32, 11]);
+    let bytes = borsh::to_vec(&set).unwrap();
+
+    let set: OrdBySet<u32, ByLastDigit> = borsh::from_slice(&bytes).unwrap();
+    assert!(set.is_sorted());
+    assert_eq!(set.count(&1), 2);
+    assert_eq!(set.last(), Some(&13));
+
+    // items serialized out of order are re-sorted when deserialized
+    let bytes = borsh::to_vec(&[9u32, 5, 7][..]).unwrap();
+    let set: OrdBySet<u32, ByLastDigit> = borsh::from_slice(&bytes).unwrap();
+    assert_eq!(set.as_slice(), [5, 7, 9]);
+}
+
+#[test]
+fn try_insert_matches_insert() {
+    let mut inserted = loose_set([(2, 'a'), (1, 'b')]);
+    let mut try_inserted = inserted.clone();
+
+    inserted.insert((2, 'c'));
+    try_inserted.try_insert((2, 'c')).unwrap();
+
+    assert_eq!(inserted.as_slice(), try_inserted.as_slice());
+}
+
+#[test]
+fn nth_is_positional() {
+    let mut set = ord_set([30, 10, 20]);
+
+    assert_eq!(set.nth(0), set.first());
+    assert_eq!(set.nth(set.len() - 1), set.last());
+    assert!(set.nth_mut(3).is_none());
+
+    *set.nth_mut(0).unwrap() = 40;
+    assert_eq!(set.as_slice(), [20, 30, 40]);
+}
+
+#[test]
+fn index_is_positional() {
+    let set = OrdBySet::new_with_order(FullOrd.reverse()).with_items([2, 9, 4]);
+
+    assert_eq!(set[0], 9);
+    assert_eq!(set[2], 2);
+}
+
+#[test]
+fn retain_in_range_leaves_outside_untouched() {
+    let mut set = loose_set([(1, 'a'), (2, 'b'), (2, 'c'), (3, 'd'), (4, 'e')]);
+    set.retain_in_range(&(2, ' '), &(3, ' '), |item| item.1 == 'c');
+
+    assert_eq!(set.as_slice(), [(1, 'a'), (2, 'c'), (4, 'e')]);
+    assert!(set.is_sorted());
+}
+
+#[test]
+fn checked_constructor_finds_intransitive_order() {
+    use core::cmp::Ordering;
+
+    // rock-paper-scissors: each item is less than the next, wrapping around
+    let cyclic = |left: &u32, right: &u32| {
+        if left == right {
+            Ordering::Equal
+        } else if (left + 1) % 3 == *right {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        }
+    };
+
+    let err = OrdBySet::try_from_vec_checked(cyclic, vec![0, 1, 2]).unwrap_err();
+    assert_eq!(
+        err,
+        OrderContractError::Transitivity {
+            first: 0,
+            second: 1,
+            third: 2
+        }
+    );
+
+    // items within 3 of each other are compared normally, but anything further apart is
+    // equivelant, which only shows up when comparing non-adjacent items
+    let nearsighted = |left: &u32, right: &u32| {
+        if left.abs_diff(*right) < 4 {
+            left.cmp(right)
+        } else {
+            Ordering::Equal
+        }
+    };
+
+    let err = OrdBySet::try_from_vec_checked(nearsighted, (0..8).collect()).unwrap_err();
+    assert_eq!(
+        err,
+        OrderContractError::Transitivity {
+            first: 0,
+            second: 2,
+            third: 4
+        }
+    );
+
+    let set = OrdBySet::try_from_vec_checked(FullOrd, vec![2, 0, 1, 1]).unwrap();
+    assert_eq!(set.as_slice(), [0, 1, 1, 2]);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "item 0 is ordered after item 1")]
+fn debug_build_panics_on_broken_order() {
+    let always_greater = |_: &u32, _: &u32| core::cmp::Ordering::Greater;
+
+    OrdBySet::new_with_order(always_greater).with_items([1, 2]);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "unsorted items were provided as sorted")]
+fn debug_build_panics_on_unsorted_merge() {
+    let mut set = ord_set([1, 3]);
+    set.merge_sorted(vec![4, 2]);
+}
+
+#[test]
+fn first_and_last_groups() {
+    let set = loose_set([(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (3, 'e')]);
+
+    assert_eq!(set.first_group(), set.groups().next());
+    assert_eq!(set.last_group(), Some(&[(3, 'e')][..]));
+
+    let empty = loose_set([]);
+    assert_eq!(empty.first_group(), None);
+    assert_eq!(empty.last_group(), None);
+}
+
+#[test]
+fn pop_groups_from_either_end() {
+    let mut set = loose_set([(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (3, 'e')]);
+
+    assert_eq!(set.pop_last_group(), [(3, 'e')]);
+    assert_eq!(set.pop_first_group().len(), 2);
+    assert_eq!(set.as_slice(), [(2, 'a'), (2, 'c')]);
+    assert_eq!(set.pop_last_group().len(), 2);
+    assert!(set.pop_last_group().is_empty());
+}
+
+#[test]
+fn truncate_keeps_smallest() {
+    let mut set = ord_set([4, 2, 3, 1]);
+    let capacity = set.capacity();
+
+    set.truncate(2);
+    assert_eq!(set.as_slice(), [1, 2]);
+    assert_eq!(set.capacity(), capacity);
+
+    set.truncate(5);
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn keep_largest_and_smallest() {
+    let mut set = ord_set([4, 2, 3, 1, 5]);
+
+    set.keep_largest(4);
+    assert_eq!(set.as_slice(), [2, 3, 4, 5]);
+
+    set.keep_smallest(3);
+    assert_eq!(set.as_slice(), [2, 3, 4]);
+
+    set.keep_largest(10);
+    set.keep_smallest(10);
+    assert_eq!(set.as_slice(), [2, 3, 4]);
+}
+
+#[test]
+fn merge_sorted_stream() {
+    let mut set = loose_set([(5, 'a'), (1, 'b'), (3, 'c')]);
+    set.merge_sorted(vec![(0, 'd'), (3, 'e'), (6, 'f')]);
+
+    assert!(set.is_sorted());
+    assert_eq!(
+        set.as_slice(),
+        [(0, 'd'), (1, 'b'), (3, 'c'), (3, 'e'), (5, 'a'), (6, 'f')]
+    );
+}
+
+#[test]
+fn extend_from_refs() {
+    let mut set = ord_set([3, 1]);
+    set.extend(&[4, 2, 3]);
+
+    assert_eq!(set.as_slice(), [1, 2, 3, 3, 4]);
+}
+
+#[test]
+fn collect_sorted_and_unsorted() {
+    let sorted: OrdBySet<(u32, char), ByKey> =
+        vec![(1, 'b'), (1, 'a'), (2, 'c')].into_iter().collect();
+    assert_eq!(sorted.as_slice(), [(1, 'b'), (1, 'a'), (2, 'c')]);
+
+    let unsorted: OrdBySet<(u32, char), ByKey> = vec![(2, 'c'), (1, 'b')].into_iter().collect();
+    assert_eq!(unsorted.as_slice(), [(1, 'b'), (2, 'c')]);
+}
+
+/// Counts how many times items are compared, so tests can check when sorting is skipped
+#[derive(Default)]
+struct CountingOrd(core::cell::Cell<usize>);
+
+impl Order<u32> for CountingOrd {
+    fn order_of(&self, left: &u32, right: &u32) -> core::cmp::Ordering {
+        self.0.set(self.0.get() + 1);
+        left.cmp(right)
+    }
+}
+
+#[test]
+fn collect_sorted_skips_sort() {
+    let sorted: OrdBySet<u32, CountingOrd> = (0..100).collect();
+
+    // collecting compares each adjacent pair once, and debug builds check each pair again
+    let pairs = sorted.len() - 1;
+    let max_calls = if cfg!(debug_assertions) {
+        2 * pairs
+    } else {
+        pairs
+    };
+    assert!(sorted.orderer.0.get() <= max_calls);
+
+    let unsorted: OrdBySet<u32, CountingOrd> = (0..100).rev().collect();
+    assert!(unsorted.orderer.0.get() > max_calls);
+    assert!(unsorted.is_sorted());
+}
+
+#[test]
+fn iter_rev_is_descending() {
+    let set = loose_set([(1, 'a'), (3, 'b'), (2, 'c')]);
+    let keys = set.iter_rev().map(|item| item.0).collect::<Vec<_>>();
+
+    assert_eq!(keys, [3, 2, 1]);
+}
+
+#[test]
+fn iter_sorted_is_ascending() {
+    let set = loose_set([(1, 'a'), (3, 'b'), (2, 'c')]);
+    let keys = set.iter_sorted().map(|item| item.0).collect::<Vec<_>>();
+
+    assert_eq!(keys, [1, 2, 3]);
+}
+
+#[test]
+fn position_of_specific() {
+    let set = loose_set([(1, 'a'), (2, 'b'), (2, 'c'), (3, 'd')]);
+
+    assert_eq!(set.position_of(&(2, 'c')), Some(2));
+    assert_eq!(set.nth(2), Some(&(2, 'c')));
+    assert_eq!(set.position_of(&(2, 'd')), None);
+    assert_eq!(set.position_of(&(4, 'd')), None);
+}
+
+#[test]
+fn remove_all_specific_keeps_loosely_equal() {
+    let mut set = loose_set([(1, 'a'), (2, 'b'), (2, 'c'), (2, 'b'), (3, 'd')]);
+
+    assert_eq!(set.remove_all_specific(&(2, 'b')), 2);
+    assert_eq!(set.as_slice(), [(1, 'a'), (2, 'c'), (3, 'd')]);
+    assert_eq!(set.remove_all_specific(&(2, 'b')), 0);
+    assert_eq!(set.remove_all_specific(&(4, 'b')), 0);
+}
+
+#[test]
+fn count_specific_differs_from_count() {
+    let set = loose_set([(2, 'b'), (2, 'c'), (2, 'b'), (3, 'd')]);
+
+    assert_eq!(set.count(&(2, 'b')), 3);
+    assert_eq!(set.count_specific(&(2, 'b')), 2);
+    assert_eq!(set.count_specific(&(2, 'e')), 0);
+    assert_eq!(set.count_specific(&(4, 'b')), 0);
+}
+
+#[test]
+fn contains_specific_requires_equality() {
+    let set = loose_set([(1, 'a'), (2, 'b')]);
+
+    assert!(set.contains(&(2, 'c')));
+    assert!(!set.contains_specific(&(2, 'c')));
+    assert!(set.contains_specific(&(2, 'b')));
+    assert!(!set.contains_specific(&(3, 'b')));
+}
+
+#[test]
+fn get_all_specific_filters_group() {
+    let set = loose_set([(2, 'b'), (2, 'c'), (2, 'b'), (3, 'd')]);
+
+    assert_eq!(set.get_all_specific(&(2, 'b')), [&(2, 'b'), &(2, 'b')]);
+    assert_eq!(set.get_all_specific(&(2, 'c')), [&(2, 'c')]);
+    assert!(set.get_all_specific(&(2, 'e')).is_empty());
+    assert!(set.get_all_specific(&(4, 'b')).is_empty());
+}
+
+#[test]
+fn unmodified_slice_guard_skips_sort() {
+    use core::cell::Cell;
+
+    let calls = Cell::new(0);
+    let counting = |left: &u32, right: &u32| {
+        calls.set(calls.get() + 1);
+        left.cmp(right)
+    };
+    let mut set = OrdBySet::new_with_order(counting).with_items([3, 1, 2, 2]);
+
+    let guard = set.get_mut(&2).unwrap();
+    assert_eq!(*guard, [2, 2]);
+
+    let before = calls.get();
+    drop(guard);
+    assert_eq!(calls.get(), before);
+
+    set.get_mut(&2).unwrap()[0] = 4;
+    assert_eq!(set.as_slice(), [1, 2, 3, 4]);
+}
+
+#[test]
+fn mut_ref_guard_only_moves_modified_item() {
+    use core::cell::Cell;
+
+    let calls = Cell::new(0);
+    let counting = |left: &(u32, char), right: &(u32, char)| {
+        calls.set(calls.get() + 1);
+        left.0.cmp(&right.0)
+    };
+    let items = (0..10).map(|key| (key, 'a')).collect::<Vec<_>>();
+    let mut set = OrdBySet::new_with_order(counting).with_items(items);
+
+    // debug builds also compare every adjacent pair once the guard is dropped
+    let pairs = set.len() - 1;
+    let debug_check = if cfg!(debug_assertions) { pairs } else { 0 };
+
+    // changing a non-key field only needs to check the item's neighbors
+    let mut guard = set.get_first_mut(&(5, ' ')).unwrap();
+    (*guard).1 = 'b';
+    let before = calls.get();
+    drop(guard);
+
+    assert!(calls.get() - before <= 2 + debug_check);
+    assert_eq!(set.get_first(&(5, ' ')), Some(&(5, 'b')));
+
+    (*set.nth_mut(2).unwrap()).0 = 7;
+    (*set.nth_mut(8).unwrap()).0 = 1;
+    let keys = set.iter().map(|item| item.0).collect::<Vec<_>>();
+    assert_eq!(keys, [0, 1, 1, 3, 4, 5, 6, 7, 7, 9]);
+}
+
+#[test]
+fn get_first_mut_restores_order() {
+    let mut set = loose_set([(1, 'a'), (2, 'b'), (3, 'c')]);
+    (*set.get_first_mut(&(1, ' ')).unwrap()).0 = 4;
+
+    assert!(set.is_sorted());
+    assert_eq!(set.last(), Some(&(4, 'a')));
+}
+
+#[test]
+fn get_specific_mut_guards_matched_item() {
+    let mut set = loose_set([(1, 'a'), (2, 'b'), (2, 'c'), (3, 'd')]);
+
+    let mut guard = set.get_specific_mut(&(2, 'c')).unwrap();
+    assert_eq!(*guard, (2, 'c'));
+    (*guard).0 = 0;
+    drop(guard);
+
+    assert!(set.is_sorted());
+    assert_eq!(set.as_slice(), [(0, 'c'), (1, 'a'), (2, 'b'), (3, 'd')]);
+}
+
+#[test]
+fn resort_after_leaked_guard() {
+    let mut set = ord_set([1, 2, 3]);
+
+    let mut guard = set.get_mut(&1).unwrap();
+    guard[0] = 4;
+    core::mem::forget(guard);
+
+    set.resort();
+    assert_eq!(set.as_slice(), [2, 3, 4]);
+}
+
+#[test]
+fn resort_after_iter_mut() {
+    let mut set = loose_set([(1, 'a'), (2, 'b'), (3, 'c')]);
+    for item in set.iter_mut() {
+        item.0 = 5 - item.0;
+    }
+    assert!(!set.is_sorted());
+
+    set.resort();
+    assert!(set.is_sorted());
+    assert_eq!(set.first(), Some(&(2, 'c')));
+}
+
+#[test]
+fn iter_mut_guarded_resorts() {
+    let mut set = loose_set([(1, 'a'), (2, 'b'), (3, 'c')]);
+    for item in set.iter_mut_guarded().iter_mut() {
+        item.0 = 5 - item.0;
+    }
+
+    assert!(set.is_sorted());
+    assert_eq!(set.first(), Some(&(2, 'c')));
+}
+
+#[test]
+fn empty_queries_skip_orderer() {
+    let never_called = |_: &u32, _: &u32| -> core::cmp::Ordering { unreachable!() };
+    let set = OrdBySet::new_with_order(never_called);
+
+    assert_eq!(set.get(&1), None);
+    assert_eq!(set.count(&1), 0);
+    assert!(!set.contains(&1));
+    assert_eq!(set.range(&1, &2), None);
+}
+
+#[test]
+fn get_or_insert_returns_existing() {
+    let mut set = loose_set([(1, 'a'), (2, 'b'), (2, 'c')]);
+
+    assert_eq!(set.get_or_insert((2, 'd')), &(2, 'b'));
+    assert_eq!(set.len(), 3);
+
+    assert_eq!(set.get_or_insert((0, 'e')), &(0, 'e'));
+    assert_eq!(set.first(), Some(&(0, 'e')));
+    assert_eq!(set.len(), 4);
+}
+
+#[test]
+fn swap_remove_first_keeps_order() {
+    let mut set = loose_set([(1, 'a'), (2, 'b'), (2, 'c'), (3, 'd'), (4, 'e')]);
+
+    assert_eq!(set.swap_remove_first(&(2, ' ')), Some((2, 'b')));
+    assert!(set.is_sorted());
+    assert_eq!(set.as_slice(), [(1, 'a'), (2, 'c'), (3, 'd'), (4, 'e')]);
+
+    assert_eq!(set.swap_remove_first(&(4, ' ')), Some((4, 'e')));
+    assert_eq!(set.swap_remove_first(&(5, ' ')), None);
+    assert_eq!(set.len(), 3);
+}
+
+#[test]
+fn drain_all_keeps_capacity() {
+    let mut set = ord_set([3, 1, 2]);
+    let capacity = set.capacity();
+
+    drop(set.drain_all().take(1));
+    assert!(set.is_empty());
+    assert_eq!(set.capacity(), capacity);
+
+    set.insert(4);
+    assert_eq!(set.as_slice(), [4]);
+}
+
+#[test]
+fn retain_groups_by_size() {
+    let mut set = ord_set([1, 2, 2, 3]);
+    set.retain_groups(|group| group.len() > 1);
+    assert_eq!(set.as_slice(), [2, 2]);
+
+    let mut set = loose_set([(1, 'a'), (2, 'b'), (2, 'c'), (3, 'd'), (3, 'e'), (4, 'f')]);
+    set.retain_groups(|group| group.iter().any(|item| item.1 != 'b'));
+    assert_eq!(set.len(), 6);
+
+    set.retain_groups(|group| group[0].0 % 2 == 1);
+    assert_eq!(set.as_slice(), [(1, 'a'), (3, 'd'), (3, 'e')]);
+}
+
+#[test]
+fn fold_groups_collects_each_group() {
+    let set = loose_set([(2, 'a'), (1, 'b'), (2, 'c'), (3, 'd')]);
+    let chars = set.fold_groups(Vec::new, |mut acc, item| {
+        acc.push(item.1);
+        acc
+    });
+
+    assert_eq!(chars, [vec!['b'], vec!['a', 'c'], vec!['d']]);
+    assert!(loose_set([]).fold_groups(|| 0, |acc, _| acc + 1).is_empty());
+}
+
+#[test]
+fn equal_sets_hash_identically() {
+    use alloc::vec::Vec;
+    use core::hash::{Hash, Hasher};
+
+    #[derive(Default)]
+    struct Recorder(Vec<u8>);
+
+    impl Hasher for Recorder {
+        fn finish(&self) -> u64 {
+            0
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            self.0.extend_from_slice(bytes);
+        }
+    }
+
+    let hash_of = |set: &OrdBySet<(u32, char), ByKey>| {
+        let mut hasher = Recorder::default();
+        set.hash(&mut hasher);
+        hasher.0
+    };
+
+    let left = loose_set([(1, 'a'), (2, 'b'), (2, 'c')]);
+    let right = loose_set([(1, 'a'), (2, 'b'), (2, 'd')]);
+    let longer = loose_set([(1, 'a'), (2, 'b'), (2, 'c'), (2, 'd')]);
+
+    assert!(left == right);
+    assert_eq!(hash_of(&left), hash_of(&right));
+    assert_ne!(hash_of(&left), hash_of(&longer));
+}
+
+#[test]
+fn sets_compare_lexicographically() {
+    assert!(ord_set([1, 2]) < ord_set([1, 3]));
+    assert!(ord_set([1]) < ord_set([1, 2]));
+    assert!(ord_set([2]) > ord_set([1, 2]));
+
+    let left = loose_set([(1, 'a'), (2, 'b')]);
+    let right = loose_set([(1, 'c'), (2, 'd')]);
+    assert_eq!(left.cmp(&right), core::cmp::Ordering::Equal);
+    assert!(left == right);
+}
+
+#[test]
+fn batch_membership() {
+    let set = loose_set([(1, 'a'), (2, 'b')]);
+    let present = [(1, 'x'), (2, 'y')];
+    let mixed = [(2, 'x'), (3, 'y')];
+
+    assert!(set.contains_all(&present));
+    assert!(!set.contains_all(&mixed));
+    assert!(set.contains_any(&mixed));
+    assert!(!set.contains_any(&[(4, 'z')]));
+    assert!(set.contains_all(&[]));
+    assert!(!set.contains_any(&[]));
+}
+
+#[test]
+fn extract_complete_groups() {
+    let mut set = loose_set([(1, 'a'), (2, 'b'), (2, 'c'), (3, 'd'), (3, 'e'), (4, 'f')]);
+    let extracted = set.extract_groups(|group| group.len() == 2);
+
+    assert_eq!(
+        extracted,
+        [vec![(2, 'b'), (2, 'c')], vec![(3, 'd'), (3, 'e')]]
+    );
+    assert_eq!(set.as_slice(), [(1, 'a'), (4, 'f')]);
+    assert!(set.extract_groups(|_| false).is_empty());
+}
+
+#[test]
+fn from_sorted_iter_trusts_order() {
+    let set = OrdBySet::from_sorted_iter(ByKey, vec![(1, 'b'), (1, 'a'), (2, 'c')]);
+
+    assert_eq!(set.as_slice(), [(1, 'b'), (1, 'a'), (2, 'c')]);
+    assert_eq!(set.count(&(1, ' ')), 2);
+}
+
+#[test]
+fn split_at_position() {
+    let mut set = loose_set([(1, 'a'), (2, 'b'), (2, 'c'), (3, 'd')]);
+    let split = set.split_at(2);
+
+    assert_eq!(set.as_slice(), [(1, 'a'), (2, 'b')]);
+    assert_eq!(split.as_slice(), [(2, 'c'), (3, 'd')]);
+    assert!(set.split_at(2).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn split_at_out_of_bounds() {
+    ord_set([1, 2]).split_at(3);
+}
+
+#[test]
+fn chunks_ignore_groups() {
+    let set = ord_set([1, 1, 1, 2]);
+    let chunks = set.chunks(2).collect::<Vec<_>>();
+
+    assert_eq!(chunks, [&[1, 1][..], &[1, 2][..]]);
+}
+
+#[test]
+fn windows_are_neighbors() {
+    let set = ord_set([3, 1, 2]);
+    let windows = set.windows(2).collect::<Vec<_>>();
+
+    assert_eq!(windows, [&[1, 2][..], &[2, 3][..]]);
+    assert_eq!(set.windows(4).count(), 0);
+}
+
+#[test]
+fn find_group_by_predicate() {
+    let set = loose_set([(1, 'a'), (2, 'b'), (4, 'c'), (5, 'd'), (7, 'e')]);
+    let middle = set.find_group_by(|item| match item.0 {
+        0..=1 => core::cmp::Ordering::Less,
+        2..=5 => core::cmp::Ordering::Equal,
+        _ => core::cmp::Ordering::Greater,
+    });
+
+    assert_eq!(middle, Some(&[(2, 'b'), (4, 'c'), (5, 'd')][..]));
+    assert_eq!(set.find_group_by(|_| core::cmp::Ordering::Less), None);
+}
+
+#[test]
+fn groups_mut_sorts_each_group() {
+    let mut set = loose_set([(1, 'a'), (1, 'b'), (2, 'c'), (2, 'd')]);
+
+    let mut groups = set.groups_mut();
+    let mut count = 0;
+    while let Some(mut group) = groups.next_group() {
+        group.swap(0, 1);
+        count += 1;
+    }
+
+    assert_eq!(count, 2);
+    assert_eq!(set.as_slice(), [(1, 'b'), (1, 'a'), (2, 'd'), (2, 'c')]);
+}
+
+#[test]
+fn shrink_policy_frees_capacity() {
+    let items = (0..64).collect::<alloc::vec::Vec<_>>();
+
+    let mut never = OrdBySet::fully_ordered().with_items(items.clone());
+    let capacity = never.capacity();
+    never.remove_range(&0, &59);
+    assert_eq!(never.capacity(), capacity);
+
+    let mut shrinking = OrdBySet::fully_ordered().with_items(items);
+    shrinking.set_shrink_policy(ShrinkPolicy::WhenQuarterFull);
+
+    // still at least a quarter full
+    shrinking.remove_range(&0, &40);
+    assert_eq!(shrinking.capacity(), capacity);
+
+    shrinking.drain(&41);
+    shrinking.remove_all(&42);
+    assert_eq!(shrinking.drain_range(&43, &50).len(), 8);
+    assert!(shrinking.capacity() < capacity);
+    assert_eq!(shrinking.len(), 13);
+}
+
+#[test]
+fn shrink_policy_covers_entries_and_splits() {
+    let items = (0..64).collect::<alloc::vec::Vec<_>>();
+    let mut set = OrdBySet::fully_ordered().with_items(items);
+    set.set_shrink_policy(ShrinkPolicy::WhenQuarterFull);
+    let capacity = set.capacity();
+
+    // splitting leaves `self` under a quarter full, and the new half keeps the policy
+    let mut split = set.split_at(4);
+    assert!(set.capacity() < capacity);
+    let capacity = split.capacity();
+
+    for item in 4..54 {
+        if let Entry::Occupied(entry) = split.entry(item) {
+            entry.remove_all();
+        }
+    }
+    assert!(split.capacity() < capacity);
+    assert_eq!(split.len(), 10);
+}
+
+#[test]
+fn group_range_slices_storage() {
+    let set = loose_set([(1, 'a'), (2, 'b'), (2, 'c'), (3, 'd')]);
+    let range = set.group_range(&(2, ' ')).unwrap();
+
+    assert_eq!(&set.as_slice()[range.clone()], set.get(&(2, ' ')).unwrap());
+    assert_eq!(set.nth(range.start), set.get_first(&(2, ' ')));
+    assert_eq!(set.group_range(&(0, ' ')), None);
+}
+
+#[test]
+fn dedup_by_within_groups() {
+    let mut set = loose_set([(1, 'a'), (1, 'A'), (1, 'b'), (2, 'B'), (2, 'b')]);
+    set.dedup_by(|item, kept| item.0 == kept.0 && item.1.eq_ignore_ascii_case(&kept.1));
+
+    assert_eq!(set.as_slice(), [(1, 'a'), (1, 'b'), (2, 'B')]);
+}
+
+#[test]
+fn insert_many_shuffled_batch() {
+    let mut set = OrdBySet::fully_ordered().with_items((0..200).map(|i| i * 2).collect::<Vec<_>>());
+    let batch = (0..50).map(|i| (i * 37) % 100).collect::<Vec<_>>();
+
+    set.insert_many(batch);
+
+    assert!(set.is_sorted());
+    assert_eq!(set.len(), 250);
+    assert_eq!(set.count(&36), 2);
+    assert_eq!(set.count(&1), 0);
+    assert_eq!(set.count(&37), 1);
+}
+
+#[test]
+fn cap_group_size_keeps_first() {
+    let mut set = ord_set([1, 1, 1, 1, 2]);
+    assert_eq!(set.cap_group_size(2), 2);
+    assert_eq!(set.as_slice(), [1, 1, 2]);
+
+    let mut set = loose_set([(1, 'a'), (1, 'b'), (1, 'c'), (2, 'd'), (2, 'e')]);
+    assert_eq!(set.cap_group_size(1), 3);
+    assert_eq!(set.as_slice(), [(1, 'a'), (2, 'd')]);
+    assert_eq!(set.cap_group_size(0), 2);
+    assert!(set.is_empty());
+
+    let mut set = ord_set([1, 1, 2, 2, 3]);
+    assert_eq!(set.cap_group_size(usize::MAX), 0);
+    assert_eq!(set.as_slice(), [1, 1, 2, 2, 3]);
+    assert_eq!(set.cap_group_size(0), 5);
+    assert!(set.is_empty());
+}
+
+#[test]
+fn with_orderer_resorts() {
+    let set = ord_set([(2, 'a'), (1, 'c'), (3, 'b')]);
+    let by_char = |left: &(u32, char), right: &(u32, char)| left.1.cmp(&right.1);
+
+    let set = set.with_orderer(by_char);
+    assert_eq!(set.as_slice(), [(2, 'a'), (3, 'b'), (1, 'c')]);
+    assert!(set.contains(&(0, 'b')));
+}
+
+#[test]
+fn distinct_count_in_range() {
+    let set = ord_set([1, 1, 2, 3, 3]);
+
+    assert_eq!(set.distinct_count_range(&1, &3), 3);
+    assert_eq!(set.distinct_count_range(&3, &3), 1);
+    assert_eq!(set.distinct_count_range(&4, &9), 0);
+    assert_eq!(set.distinct_count_range(&3, &1), 0);
+
+    let set = loose_set([(1, 'a'), (2, 'b'), (2, 'c'), (4, 'd')]);
+    assert_eq!(set.distinct_count_range(&(2, ' '), &(4, ' ')), 2);
+}
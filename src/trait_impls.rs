@@ -1,7 +1,10 @@
 use crate::{OrdBySet, Order};
 use alloc::vec::Vec;
+use core::cmp::Ordering;
 use core::fmt::Debug;
+use core::hash::{Hash, Hasher};
 use core::iter::FromIterator;
+use core::ops::Index;
 
 impl<T, Orderer: Order<T>> IntoIterator for OrdBySet<T, Orderer> {
     type IntoIter = alloc::vec::IntoIter<T>;
@@ -12,19 +15,98 @@ impl<T, Orderer: Order<T>> IntoIterator for OrdBySet<T, Orderer> {
     }
 }
 
+impl<'a, T, Orderer: Order<T>> IntoIterator for &'a OrdBySet<T, Orderer> {
+    type IntoIter = core::slice::Iter<'a, T>;
+    type Item = &'a T;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.storage.iter()
+    }
+}
+
+/// Iterates mutably over each item, the same as [`OrdBySet::iter_mut`]. Like
+/// `iter_mut`, the state of the set is unspecified if the ordering of any item is changed.
+impl<'a, T, Orderer: Order<T>> IntoIterator for &'a mut OrdBySet<T, Orderer> {
+    type IntoIter = core::slice::IterMut<'a, T>;
+    type Item = &'a mut T;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.storage.iter_mut()
+    }
+}
+
 impl<T, Orderer: Order<T> + Default> From<Vec<T>> for OrdBySet<T, Orderer> {
     fn from(mut storage: Vec<T>) -> Self {
         let orderer = Orderer::default();
 
-        storage.sort_by(|left, right| orderer.order_of(&left, &right));
+        storage.sort_by(|left, right| orderer.order_of(left, right));
+
+        let set = Self::from_sorted_unchecked(orderer, storage);
+        set.debug_assert_sorted();
 
-        Self { storage, orderer }
+        set
     }
 }
 
+/// Collects the items into a set, using a default-initialized orderer to sort them. If the
+/// items are already sorted according to the orderer, the sort is skipped.
 impl<T, Orderer: Order<T> + Default> FromIterator<T> for OrdBySet<T, Orderer> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
-        Self::from(iter.into_iter().collect::<Vec<_>>())
+        let orderer = Orderer::default();
+        let iter = iter.into_iter();
+        let mut storage = Vec::with_capacity(iter.size_hint().0);
+        let mut sorted = true;
+
+        for item in iter {
+            if let Some(prev) = storage.last() {
+                sorted = sorted && orderer.order_of(prev, &item).is_le();
+            }
+
+            storage.push(item);
+        }
+
+        if !sorted {
+            orderer.sort_slice(&mut storage);
+        }
+
+        let set = Self::from_sorted_unchecked(orderer, storage);
+        set.debug_assert_sorted();
+
+        set
+    }
+}
+
+impl<T, Orderer: Order<T>> Extend<T> for OrdBySet<T, Orderer> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let prev_len = self.storage.len();
+        self.storage.extend(iter);
+
+        if self.storage.len() != prev_len {
+            self.orderer.sort_slice(&mut self.storage);
+            self.debug_assert_sorted();
+        }
+    }
+}
+
+/// Copies each item into the set, the same as `Extend<T>`.
+impl<'a, T: Copy + 'a, Orderer: Order<T>> Extend<&'a T> for OrdBySet<T, Orderer> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+/// Gets the item at the given position in sorted order, the same as [`OrdBySet::nth`].
+/// `IndexMut` is intentionally not implemented, as it could be used to break the ordering
+/// of the set.
+///
+/// ### Panics
+///
+/// Panics if `index` is out of bounds.
+impl<T, Orderer: Order<T>> Index<usize> for OrdBySet<T, Orderer> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.storage[index]
     }
 }
 
@@ -34,11 +116,63 @@ impl<T: Debug, Orderer: Order<T>> Debug for OrdBySet<T, Orderer> {
     }
 }
 
+/// Two sets are equal if they contain the same number of items and each item is
+/// equivelant, according to the orderer, to the item in the same position of the other
+/// set. For checking equality using `PartialEq`, see [`OrdBySet::eq_exact`].
+impl<T, Orderer: Order<T>> PartialEq for OrdBySet<T, Orderer> {
+    fn eq(&self, other: &Self) -> bool {
+        self.storage.len() == other.storage.len()
+            && self
+                .storage
+                .iter()
+                .zip(&other.storage)
+                .all(|(left, right)| self.orderer.order_of(left, right).is_eq())
+    }
+}
+
+impl<T, Orderer: Order<T>> Eq for OrdBySet<T, Orderer> {}
+
+impl<T, Orderer: Order<T>> PartialOrd for OrdBySet<T, Orderer> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Sets are compared lexicographically, comparing the items in each position using the
+/// orderer. If one set is a prefix of the other, the shorter set is ordered first. This
+/// is consistent with `==`, such that sets which are equal compare as `Ordering::Equal`.
+impl<T, Orderer: Order<T>> Ord for OrdBySet<T, Orderer> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.storage
+            .iter()
+            .zip(&other.storage)
+            .map(|(left, right)| self.orderer.order_of(left, right))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or_else(|| self.storage.len().cmp(&other.storage.len()))
+    }
+}
+
+/// Hashes the number of items in the set, followed by the first item and the number of
+/// items of each group of equivelant items. This ensures that sets which are equal under
+/// `==` hash identically, regardless of which items make up each group.
+///
+/// **Note:** this relies on equivelant items hashing identically, as the hash of a group
+/// is based on whichever of its items is first. If the `Hash` implementation of `T`
+/// distinguishes between items which the orderer considers equivelant, then sets which
+/// are equal under `==` may hash differently.
+impl<T: Hash, Orderer: Order<T>> Hash for OrdBySet<T, Orderer> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.storage.len().hash(state);
+
+        for group in self.groups() {
+            group[0].hash(state);
+            group.len().hash(state);
+        }
+    }
+}
+
 impl<T, Orderer: Order<T> + Default> Default for OrdBySet<T, Orderer> {
     fn default() -> Self {
-        Self {
-            storage: Vec::default(),
-            orderer: Orderer::default(),
-        }
+        Self::new_with_order(Orderer::default())
     }
 }